@@ -0,0 +1,309 @@
+use darling::{
+    ast::{Data, Style},
+    util::SpannedValue,
+};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, parse_quote, Generics, Ident, Index, LitStr, WherePredicate};
+
+use crate::{
+    automatically_derived, masking::Masking, transform_field, type_uses_param, FieldOptions,
+    Options,
+};
+
+/// Generates `Display` impl from `#[deboog(display = "...")]` templates
+pub(crate) fn display_impl(opts: &Options) -> darling::Result<TokenStream2> {
+    let ident = &opts.ident;
+    let mut displayed = Vec::new();
+    let body = match opts.data {
+        Data::Struct(ref fields) => {
+            let template = opts.display.as_ref().ok_or_else(|| {
+                darling::Error::custom("`DeboogDisplay` requires `#[deboog(display = \"...\")]`")
+                    .with_span(ident)
+            })?;
+            let bound: Vec<(TokenStream2, &FieldOptions)> = fields
+                .iter()
+                .enumerate()
+                .map(|(i, f)| match f.ident {
+                    Some(ref field) => (quote! { &self.#field }, f),
+                    None => {
+                        let i = Index::from(i);
+                        (quote! { &self.#i }, f)
+                    }
+                })
+                .collect();
+            let (template, used) = parse_template(template, &bound)?;
+            displayed.extend(used.iter().map(|&i| bound[i].1));
+            write_template(&template, &used, &bound)
+        }
+        Data::Enum(ref variants) => {
+            let mut errors = darling::Error::accumulator();
+            let arms: Vec<TokenStream2> = variants
+                .iter()
+                .filter_map(|v| {
+                    let var = &v.ident;
+                    let template = match v.display.as_ref().or(opts.display.as_ref()) {
+                        Some(template) => template,
+                        None => {
+                            errors.push(
+                                darling::Error::custom(
+                                    "variant requires `#[deboog(display = \"...\")]`, \
+                                     either on itself or on the enum",
+                                )
+                                .with_span(var),
+                            );
+                            return None;
+                        }
+                    };
+                    let binding = |i: usize| format_ident!("__self_{}", i, span = var.span());
+                    let bound: Vec<(TokenStream2, &FieldOptions)> = v
+                        .fields
+                        .iter()
+                        .enumerate()
+                        .map(|(i, f)| {
+                            let field = binding(i);
+                            (quote! { #field }, f)
+                        })
+                        .collect();
+                    let (template, used) = errors.handle(parse_template(template, &bound))?;
+                    displayed.extend(used.iter().map(|&i| bound[i].1));
+                    let pattern = match v.fields.style {
+                        Style::Unit => quote! { Self::#var },
+                        Style::Tuple => {
+                            let patterns = (0..v.fields.len()).map(|i| {
+                                if used.contains(&i) {
+                                    binding(i)
+                                } else {
+                                    Ident::new("_", var.span())
+                                }
+                            });
+                            quote! { Self::#var(#(#patterns),*) }
+                        }
+                        Style::Struct => {
+                            let patterns = used.iter().map(|&i| {
+                                let field = &v.fields.fields[i].ident;
+                                let bound = binding(i);
+                                quote! { #field: #bound }
+                            });
+                            quote! { Self::#var { #(#patterns,)* .. } }
+                        }
+                    };
+                    let write = write_template(&template, &used, &bound);
+                    Some(quote! { #pattern => #write })
+                })
+                .collect();
+            errors.finish()?;
+            quote! {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    };
+    let generics = add_display_bounds(opts, &displayed);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let derived = automatically_derived(&opts.data);
+    Ok(quote! {
+        #derived
+        impl #impl_generics std::fmt::Display for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                #body
+            }
+        }
+    })
+}
+
+/// Adds `Display` bounds for type parameters used by fields referenced in templates, and
+/// `DeboogField` bounds for type parameters used by hidden fields referenced in templates
+///
+/// Unlike other derives, parameters of fields that are never displayed don't need the bound.
+fn add_display_bounds(opts: &Options, displayed: &[&FieldOptions]) -> Generics {
+    let mut generics = opts.generics.clone();
+    let predicates: Vec<WherePredicate> = match opts.bound {
+        Some(ref bound) => bound.clone(),
+        None => generics
+            .type_params()
+            .flat_map(|param| {
+                let ident = &param.ident;
+                let uses = |filter: fn(&FieldOptions) -> bool| {
+                    displayed
+                        .iter()
+                        .any(|f| filter(f) && type_uses_param(&f.ty, ident))
+                };
+                let display: Option<WherePredicate> =
+                    uses(displays_value).then(|| parse_quote! { #ident: std::fmt::Display });
+                let hidden: Option<WherePredicate> = uses(reveals_hidden)
+                    .then(|| parse_quote! { #ident: deboog::field::DeboogField });
+                display.into_iter().chain(hidden)
+            })
+            .collect(),
+    };
+    generics.make_where_clause().predicates.extend(predicates);
+    generics
+}
+
+/// Writes rewritten template, passing referenced fields as named arguments
+fn write_template(
+    template: &LitStr,
+    used: &[usize],
+    fields: &[(TokenStream2, &FieldOptions)],
+) -> TokenStream2 {
+    let args = used.iter().map(|&i| {
+        let (ref field, f) = fields[i];
+        let name = format_ident!("__{}", i);
+        let value = display_field(field.clone(), f);
+        quote! { #name = #value }
+    });
+    quote! { write!(f, #template #(, #args)*) }
+}
+
+/// Parses display template, replacing field references with named arguments
+///
+/// Returns rewritten template and indexes of referenced fields.
+fn parse_template(
+    template: &SpannedValue<String>,
+    fields: &[(TokenStream2, &FieldOptions)],
+) -> darling::Result<(LitStr, Vec<usize>)> {
+    let span = template.span();
+    let error = |message: String| darling::Error::custom(message).with_span(&span);
+    let mut output = String::new();
+    let mut used = Vec::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                output.push_str("{{");
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                output.push_str("}}");
+            }
+            '}' => return Err(error("unmatched `}` in display template".into())),
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => return Err(error("unmatched `{` in display template".into())),
+                    }
+                }
+                let (name, spec) = match placeholder.split_once(':') {
+                    Some((name, spec)) => (name, Some(spec)),
+                    None => (placeholder.as_str(), None),
+                };
+                let index = field_index(name, fields).map_err(error)?;
+                if spec.is_some_and(|spec| spec.ends_with('?')) {
+                    return Err(error(format!(
+                        "field `{}` can't use `Debug` formatting in display template",
+                        name
+                    )));
+                }
+                if !used.contains(&index) {
+                    used.push(index);
+                }
+                output.push_str(&format!("{{__{}", index));
+                if let Some(spec) = spec {
+                    output.push(':');
+                    output.push_str(spec);
+                }
+                output.push('}');
+            }
+            c => output.push(c),
+        }
+    }
+    Ok((LitStr::new(&output, Span::call_site()), used))
+}
+
+/// Finds field referenced in the template by its name or position
+fn field_index(name: &str, fields: &[(TokenStream2, &FieldOptions)]) -> Result<usize, String> {
+    if name.is_empty() {
+        return Err("placeholders in display template must name a field".into());
+    }
+    let index = match fields.first() {
+        Some((_, f)) if f.ident.is_some() => fields.iter().position(|(_, f)| match f.ident {
+            Some(ref ident) => ident.unraw() == name,
+            None => false,
+        }),
+        _ => name.parse().ok().filter(|&i| i < fields.len()),
+    }
+    .ok_or_else(|| format!("unknown field `{}` in display template", name))?;
+    let f = fields[index].1;
+    if f.skip {
+        return Err(format!("skipped field `{}` can't be displayed", name));
+    }
+    if f.mask_keys.is_some() || f.mask_values.is_some() {
+        return Err(format!(
+            "field `{}` with `mask_keys` or `mask_values` can't be displayed",
+            name
+        ));
+    }
+    Ok(index)
+}
+
+/// Wraps a field referenced in the template to apply its masking to `Display` output
+fn display_field(field: TokenStream2, opts: &FieldOptions) -> TokenStream2 {
+    if opts.with.is_some() {
+        return transform_field(field, opts);
+    }
+    let placeholder = match opts.placeholder {
+        Some(ref placeholder) => {
+            let placeholder = placeholder.as_str();
+            quote! { #placeholder }
+        }
+        None => quote! { deboog::masking::HIDE_STR },
+    };
+    match (opts.mask, opts.bytes) {
+        // Encoded bytes are only written when hidden values are revealed
+        (Some(Masking::Hidden), Some(bytes)) => {
+            let encoding = bytes.encoding();
+            quote! {
+                &deboog::field::DisplayMasked {
+                    value: &deboog::field::MaskedBytes {
+                        value: #field,
+                        encoding: #encoding,
+                        mask_type: None,
+                    },
+                    mask_type: None,
+                    placeholder: #placeholder,
+                }
+            }
+        }
+        (Some(Masking::Hidden), None) => quote! {
+            &deboog::field::DisplayHidden {
+                value: #field,
+                placeholder: #placeholder,
+            }
+        },
+        (_, Some(_)) => transform_field(field, opts),
+        (None, None) => field,
+        (Some(mask), None) => {
+            let mask_type = match mask.mask_type() {
+                Some(mask_type) => quote! { Some(#mask_type) },
+                None => quote! { None },
+            };
+            quote! {
+                &deboog::field::DisplayMasked {
+                    value: #field,
+                    mask_type: #mask_type,
+                    placeholder: #placeholder,
+                }
+            }
+        }
+    }
+}
+
+/// Checks whether field value is written using its own `Display` impl
+///
+/// Values of hidden fields, fields rendered by a function or as bytes don't need `Display`.
+fn displays_value(opts: &FieldOptions) -> bool {
+    let hidden = matches!(opts.mask, Some(Masking::Hidden));
+    opts.with.is_none() && opts.bytes.is_none() && !hidden
+}
+
+/// Checks whether field value is written as in `Debug` output when hidden values are revealed
+fn reveals_hidden(opts: &FieldOptions) -> bool {
+    let hidden = matches!(opts.mask, Some(Masking::Hidden));
+    opts.with.is_none() && opts.bytes.is_none() && hidden
+}
@@ -1,39 +1,207 @@
 use darling::{
     ast::{Data, Fields, Style},
-    FromDeriveInput, FromField, FromMeta, FromVariant,
+    util::SpannedValue,
+    FromDeriveInput, FromField, FromVariant,
 };
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{quote, ToTokens};
-use syn::{parse_macro_input, spanned::Spanned, Ident, Index};
+use proc_macro2::TokenTree;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    ext::IdentExt, parse_macro_input, parse_quote, spanned::Spanned, Generics, Ident, Index, Path,
+    Type, WherePredicate,
+};
 
-#[derive(Clone, Copy, Default, FromMeta)]
-#[darling(default, rename_all = "snake_case")]
-enum Masking {
-    #[default]
-    All,
-    Pan,
-    PanSuffix,
-    Hidden,
-}
+use crate::{
+    masking::{BytesEncoding, Masking},
+    rename::RenameRule,
+};
+
+mod display;
+mod masking;
+mod rename;
+mod serialize;
+mod valuable;
 
 type OptionData = Data<VariantOptions, FieldOptions>;
 
 #[derive(FromDeriveInput)]
-#[darling(attributes(deboog))]
+#[darling(attributes(deboog), and_then = Options::resolve)]
 struct Options {
     ident: Ident,
+    generics: Generics,
     data: OptionData,
+    #[darling(default)]
+    bound: Option<Vec<WherePredicate>>,
+    #[darling(default)]
+    placeholder: Option<String>,
+    #[darling(default)]
+    rename: Option<SpannedValue<String>>,
+    #[darling(default)]
+    rename_all: Option<RenameRule>,
+    #[darling(default)]
+    mask: Option<Masking>,
+    #[darling(default)]
+    display: Option<SpannedValue<String>>,
+}
+
+impl Options {
+    /// Applies container-level defaults to fields and variants
+    fn resolve(mut self) -> darling::Result<Self> {
+        if let (Some(ref rename), Data::Enum(_)) = (&self.rename, &self.data) {
+            return Err(darling::Error::custom("`rename` is not supported on enums")
+                .with_span(&rename.span()));
+        }
+        if let Some(mask) = self.mask {
+            for field in all_fields_mut(&mut self.data) {
+                if !field.is_masked() && !*field.unmask && field.with.is_none() {
+                    field.mask = Some(mask);
+                }
+            }
+        }
+        if let Some(ref placeholder) = self.placeholder {
+            for field in all_fields_mut(&mut self.data) {
+                if matches!(field.mask, Some(Masking::Hidden)) && field.placeholder.is_none() {
+                    field.placeholder =
+                        Some(SpannedValue::new(placeholder.clone(), field.ty.span()));
+                }
+            }
+        }
+        if let Some(rule) = self.rename_all {
+            match self.data {
+                Data::Enum(ref mut variants) => {
+                    for variant in variants {
+                        variant.rename.get_or_insert_with(|| {
+                            SpannedValue::new(
+                                rule.apply(&variant.ident.unraw().to_string()),
+                                variant.ident.span(),
+                            )
+                        });
+                    }
+                }
+                Data::Struct(ref mut fields) => rename_fields(&mut fields.fields, rule),
+            }
+        }
+        if let Data::Enum(ref mut variants) = self.data {
+            for variant in variants {
+                if let Some(rule) = variant.rename_all {
+                    rename_fields(&mut variant.fields.fields, rule);
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Type name to use in output
+    fn name(&self) -> String {
+        match self.rename {
+            Some(ref rename) => rename.as_str().to_owned(),
+            None => self.ident.unraw().to_string(),
+        }
+    }
+}
+
+/// Applies rename rule to all named fields that were not renamed explicitly
+fn rename_fields(fields: &mut [FieldOptions], rule: RenameRule) {
+    for field in fields {
+        if let Some(ref ident) = field.ident {
+            field.rename.get_or_insert_with(|| {
+                SpannedValue::new(rule.apply(&ident.unraw().to_string()), ident.span())
+            });
+        }
+    }
 }
 
 #[derive(FromField)]
-#[darling(attributes(deboog))]
+#[darling(attributes(deboog), and_then = FieldOptions::validate)]
 struct FieldOptions {
     ident: Option<Ident>,
+    ty: Type,
     #[darling(default)]
     skip: bool,
     #[darling(default)]
     mask: Option<Masking>,
+    #[darling(default)]
+    mask_keys: Option<SpannedValue<Masking>>,
+    #[darling(default)]
+    mask_values: Option<SpannedValue<Masking>>,
+    #[darling(default)]
+    unmask: SpannedValue<bool>,
+    #[darling(default)]
+    placeholder: Option<SpannedValue<String>>,
+    #[darling(default)]
+    rename: Option<SpannedValue<String>>,
+    #[darling(default)]
+    with: Option<Path>,
+    #[darling(default)]
+    skip_if: Option<Path>,
+    #[darling(default)]
+    bytes: Option<BytesEncoding>,
+}
+
+impl FieldOptions {
+    /// Checks for conflicting field attributes
+    fn validate(self) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
+        if let Some(ref placeholder) = self.placeholder {
+            if !matches!(self.mask, Some(Masking::Hidden)) {
+                errors.push(
+                    darling::Error::custom("`placeholder` requires `mask = \"hidden\"`")
+                        .with_span(&placeholder.span()),
+                );
+            }
+        }
+        if *self.unmask && self.is_masked() {
+            errors.push(
+                darling::Error::custom("`unmask` conflicts with `mask`")
+                    .with_span(&self.unmask.span()),
+            );
+        }
+        for map_mask in [&self.mask_keys, &self.mask_values].into_iter().flatten() {
+            if self.mask.is_some() {
+                errors.push(
+                    darling::Error::custom("`mask_keys` and `mask_values` conflict with `mask`")
+                        .with_span(&map_mask.span()),
+                );
+            }
+            if map_mask.mask_type().is_none() {
+                errors.push(
+                    darling::Error::custom("`hidden` is not supported for map keys and values")
+                        .with_span(&map_mask.span()),
+                );
+            }
+        }
+        if let (Some(ref rename), None) = (&self.rename, &self.ident) {
+            errors.push(
+                darling::Error::custom("`rename` is not supported on tuple fields")
+                    .with_span(&rename.span()),
+            );
+        }
+        if let (Some(ref with), true) = (&self.with, self.is_masked() || *self.unmask) {
+            errors.push(
+                darling::Error::custom("`with` conflicts with `mask` and `unmask`").with_span(with),
+            );
+        }
+        if let (Some(ref skip_if), true) = (&self.skip_if, self.skip) {
+            errors
+                .push(darling::Error::custom("`skip_if` conflicts with `skip`").with_span(skip_if));
+        }
+        errors.finish_with(self)
+    }
+
+    /// Checks whether any kind of masking is set for the field
+    fn is_masked(&self) -> bool {
+        self.mask.is_some() || self.mask_keys.is_some() || self.mask_values.is_some()
+    }
+
+    /// Field name to use in output
+    fn name(&self) -> String {
+        match (&self.rename, &self.ident) {
+            (Some(rename), _) => rename.as_str().to_owned(),
+            (None, Some(ident)) => ident.unraw().to_string(),
+            (None, None) => String::new(),
+        }
+    }
 }
 
 #[derive(FromVariant)]
@@ -41,23 +209,105 @@ struct FieldOptions {
 struct VariantOptions {
     ident: Ident,
     fields: Fields<FieldOptions>,
+    #[darling(default)]
+    rename: Option<SpannedValue<String>>,
+    #[darling(default)]
+    rename_all: Option<RenameRule>,
+    #[darling(default)]
+    display: Option<SpannedValue<String>>,
+}
+
+impl VariantOptions {
+    /// Variant name to use in output
+    fn name(&self) -> String {
+        match self.rename {
+            Some(ref rename) => rename.as_str().to_owned(),
+            None => self.ident.unraw().to_string(),
+        }
+    }
 }
 
 #[proc_macro_derive(Deboog, attributes(deboog))]
 pub fn derive_deboog(input: TokenStream) -> TokenStream {
+    expand(input, |opts| Ok(debug_fmt_impl(opts)))
+}
+
+#[proc_macro_derive(DeboogSerialize, attributes(deboog))]
+pub fn derive_deboog_serialize(input: TokenStream) -> TokenStream {
+    expand(input, |opts| Ok(serialize::serialize_impl(opts)))
+}
+
+#[proc_macro_derive(DeboogValuable, attributes(deboog))]
+pub fn derive_deboog_valuable(input: TokenStream) -> TokenStream {
+    expand(input, |opts| Ok(valuable::valuable_impl(opts)))
+}
+
+#[proc_macro_derive(DeboogDisplay, attributes(deboog))]
+pub fn derive_deboog_display(input: TokenStream) -> TokenStream {
+    expand(input, display::display_impl)
+}
+
+/// Parses attributes of derive input and generates impl, reporting errors as compile errors
+fn expand(
+    input: TokenStream,
+    generate: impl FnOnce(&Options) -> darling::Result<TokenStream2>,
+) -> TokenStream {
     let input = parse_macro_input!(input);
-    let opts = Options::from_derive_input(&input).unwrap();
-    let debug_impl = debug_fmt_impl(&opts.ident, &opts.data);
+    let output = Options::from_derive_input(&input).and_then(|opts| {
+        let checks = feature_checks(&opts);
+        generate(&opts).map(|output| quote! { #checks #output })
+    });
+    match output {
+        Ok(output) => output.into(),
+        Err(err) => err.write_errors().into(),
+    }
+}
+
+/// Reports masking strategies that require disabled features of `deboog` crate
+fn feature_checks(opts: &Options) -> TokenStream2 {
+    let hash = all_fields(&opts.data).find(|f| {
+        [
+            f.mask,
+            f.mask_keys.as_deref().copied(),
+            f.mask_values.as_deref().copied(),
+        ]
+        .iter()
+        .any(|mask| match mask {
+            Some(Masking::Email(params)) => matches!(params.fallback, Masking::Hash),
+            mask => matches!(mask, Some(Masking::Hash)),
+        })
+    });
+    match hash {
+        Some(field) => quote_spanned! { field.ty.span()=> deboog::__require_hash_feature!{} },
+        None => quote! {},
+    }
+}
 
-    let output = quote! { #debug_impl };
-    output.into()
+/// Returns `#[automatically_derived]` attribute, unless fields reference functions
+///
+/// Dead code analysis ignores automatically derived impls, so functions referenced
+/// in `#[deboog(with = "...")]` and `#[deboog(skip_if = "...")]` would be reported as unused.
+fn automatically_derived(data: &OptionData) -> TokenStream2 {
+    if all_fields(data).any(|f| f.with.is_some() || f.skip_if.is_some()) {
+        quote! {}
+    } else {
+        quote! { #[automatically_derived] }
+    }
 }
 
-fn debug_fmt_impl(ident: &Ident, data: &OptionData) -> TokenStream2 {
-    let debug_fmt = debug_fmt_body(ident, data);
+fn debug_fmt_impl(opts: &Options) -> TokenStream2 {
+    let ident = &opts.ident;
+    let generics = add_trait_bounds(
+        opts,
+        quote! { std::fmt::Debug },
+        quote! { deboog::field::DeboogField },
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let debug_fmt = debug_fmt_body(&opts.name(), &opts.data);
+    let derived = automatically_derived(&opts.data);
     quote! {
-        #[automatically_derived]
-        impl std::fmt::Debug for #ident {
+        #derived
+        impl #impl_generics std::fmt::Debug for #ident #ty_generics #where_clause {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 #debug_fmt
             }
@@ -65,61 +315,132 @@ fn debug_fmt_impl(ident: &Ident, data: &OptionData) -> TokenStream2 {
     }
 }
 
-fn debug_fmt_body(ident: &Ident, data: &OptionData) -> TokenStream2 {
+/// Adds `bound` to all type parameters, and `masked_bound` to type parameters used in masked
+/// fields and to masked keys or values of generic maps. Associated types of type parameters, like
+/// `B::Key` in field types, are not bounded. If `#[deboog(bound = "...")]` is present, uses it
+/// instead.
+fn add_trait_bounds(opts: &Options, bound: TokenStream2, masked_bound: TokenStream2) -> Generics {
+    let mut generics = opts.generics.clone();
+    let predicates: Vec<WherePredicate> = match opts.bound {
+        Some(ref bound) => bound.clone(),
+        None => {
+            let masked_fields: Vec<&FieldOptions> = all_fields(&opts.data)
+                .filter(|f| !f.skip && f.mask.is_some())
+                .collect();
+            let param_bounds = generics.type_params().flat_map(|param| {
+                let ident = &param.ident;
+                let mut bounds: Vec<WherePredicate> = vec![parse_quote! { #ident: #bound }];
+                if masked_fields.iter().any(|f| type_uses_param(&f.ty, ident)) {
+                    bounds.push(parse_quote! { #ident: #masked_bound });
+                }
+                bounds
+            });
+            // Only the masked side of a map needs `masked_bound`, which can't be expressed
+            // on type parameters without knowing the map type
+            let map_bounds = all_fields(&opts.data)
+                .filter(|f| !f.skip)
+                .filter(|f| {
+                    generics
+                        .type_params()
+                        .any(|param| type_uses_param(&f.ty, &param.ident))
+                })
+                .flat_map(|f| {
+                    let ty = &f.ty;
+                    let keys = f.mask_keys.as_ref().map(|_| -> WherePredicate {
+                        parse_quote! { <#ty as deboog::field::DeboogMap>::Key: #masked_bound }
+                    });
+                    let values = f.mask_values.as_ref().map(|_| -> WherePredicate {
+                        parse_quote! { <#ty as deboog::field::DeboogMap>::Value: #masked_bound }
+                    });
+                    keys.into_iter().chain(values)
+                });
+            param_bounds.chain(map_bounds).collect()
+        }
+    };
+    generics.make_where_clause().predicates.extend(predicates);
+    generics
+}
+
+/// Iterates over fields of a struct or of all enum variants
+fn all_fields(data: &OptionData) -> Box<dyn Iterator<Item = &FieldOptions> + '_> {
+    match data {
+        Data::Enum(variants) => Box::new(variants.iter().flat_map(|v| v.fields.iter())),
+        Data::Struct(fields) => Box::new(fields.iter()),
+    }
+}
+
+/// Mutably iterates over fields of a struct or of all enum variants
+fn all_fields_mut(data: &mut OptionData) -> Box<dyn Iterator<Item = &mut FieldOptions> + '_> {
+    match data {
+        Data::Enum(variants) => {
+            Box::new(variants.iter_mut().flat_map(|v| v.fields.fields.iter_mut()))
+        }
+        Data::Struct(fields) => Box::new(fields.fields.iter_mut()),
+    }
+}
+
+/// Checks whether type parameter is mentioned anywhere in the field type
+fn type_uses_param(ty: &Type, param: &Ident) -> bool {
+    fn walk(tokens: TokenStream2, param: &Ident) -> bool {
+        tokens.into_iter().any(|tt| match tt {
+            TokenTree::Ident(ref i) => i == param,
+            TokenTree::Group(g) => walk(g.stream(), param),
+            _ => false,
+        })
+    }
+    walk(ty.to_token_stream(), param)
+}
+
+fn debug_fmt_body(ident_str: &str, data: &OptionData) -> TokenStream2 {
     match data {
         Data::Enum(variants) => debug_fmt_enum(variants),
         Data::Struct(fields) => match fields.style {
-            Style::Unit => debug_fmt_unit_struct(ident),
-            Style::Struct => debug_fmt_normal_struct(ident, &fields.fields),
-            Style::Tuple => debug_fmt_tuple_struct(ident, &fields.fields),
+            Style::Unit => debug_fmt_unit_struct(ident_str),
+            Style::Struct => debug_fmt_normal_struct(ident_str, &fields.fields),
+            Style::Tuple => debug_fmt_tuple_struct(ident_str, &fields.fields),
         },
     }
 }
 
-fn debug_fmt_unit_struct(ident: &Ident) -> TokenStream2 {
-    let ident_str = ident.to_string();
+fn debug_fmt_unit_struct(ident_str: &str) -> TokenStream2 {
     quote! {
         f.debug_struct(#ident_str).finish()
     }
 }
 
-fn debug_fmt_normal_struct(ident: &Ident, fields: &[FieldOptions]) -> TokenStream2 {
-    let ident_str = ident.to_string();
+fn debug_fmt_normal_struct(ident_str: &str, fields: &[FieldOptions]) -> TokenStream2 {
     let field_chunks = fields.iter().filter(|f| !f.skip).map(|f| {
         let field = &f.ident;
-        let field_str = field.to_token_stream().to_string();
-        let field_val = transform_field(quote! { &self.#field }, f);
-        quote! { .field(#field_str, #field_val) }
+        field_chunk(quote! { &self.#field }, Some(f.name()), f)
     });
     quote! {
-        f.debug_struct(#ident_str)
-            #(#field_chunks)*
-            .finish()
+        let mut builder = f.debug_struct(#ident_str);
+        #(#field_chunks)*
+        builder.finish()
     }
 }
 
-fn debug_fmt_tuple_struct(ident: &Ident, fields: &[FieldOptions]) -> TokenStream2 {
-    let ident_str = ident.to_string();
+fn debug_fmt_tuple_struct(ident_str: &str, fields: &[FieldOptions]) -> TokenStream2 {
     let field_chunks = fields
         .iter()
         .enumerate()
         .filter(|(_, f)| !f.skip)
         .map(|(i, f)| {
             let i = Index::from(i);
-            let field_val = transform_field(quote! { &self.#i }, f);
-            quote! { .field(#field_val) }
+            field_chunk(quote! { &self.#i }, None, f)
         });
     quote! {
-        f.debug_tuple(#ident_str)
-            #(#field_chunks)*
-            .finish()
+        let mut builder = f.debug_tuple(#ident_str);
+        #(#field_chunks)*
+        builder.finish()
     }
 }
 
 fn debug_fmt_enum(variants: &[VariantOptions]) -> TokenStream2 {
     let variant_chunks = variants.iter().map(|v| {
         let var = &v.ident;
-        let var_str = var.to_string();
+        let var_str = v.name();
+        let binding = |i: usize| Ident::new(&format!("__self_{}", i), v.ident.span());
 
         if v.fields.is_unit() {
             quote! {
@@ -132,33 +453,50 @@ fn debug_fmt_enum(variants: &[VariantOptions]) -> TokenStream2 {
                 if f.skip {
                     Ident::new("_", v.ident.span())
                 } else {
-                    Ident::new(&format!("f{}", i), v.ident.span())
+                    binding(i)
                 }
             });
-            let field_chunks = v
-                .fields
-                .iter()
-                .enumerate()
-                .filter(|(_, f)| !f.skip)
-                .map(|(i, f)| Ident::new(&format!("f{}", i), f.ident.span()));
+            let field_chunks =
+                v.fields
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| !f.skip)
+                    .map(|(i, f)| {
+                        let field = binding(i);
+                        field_chunk(quote! { #field }, None, f)
+                    });
             quote! {
                 Self::#var(#(#fields),*) => {
-                    f.debug_tuple(#var_str)
-                        #(.field(#field_chunks))*
-                        .finish()
+                    let mut builder = f.debug_tuple(#var_str);
+                    #(#field_chunks)*
+                    builder.finish()
                 }
             }
         } else {
-            let fields = v.fields.iter().filter(|f| !f.skip).map(|f| &f.ident);
-            let variant_fields = fields.clone().map(|i| {
-                let field_str = i.to_token_stream().to_string();
-                quote! { .field(#field_str, #i) }
-            });
+            let fields = v
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| !f.skip)
+                .map(|(i, f)| {
+                    let field = &f.ident;
+                    let bound = binding(i);
+                    quote! { #field: #bound }
+                });
+            let field_chunks =
+                v.fields
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| !f.skip)
+                    .map(|(i, f)| {
+                        let field = binding(i);
+                        field_chunk(quote! { #field }, Some(f.name()), f)
+                    });
             quote! {
                 Self::#var { #(#fields,)* .. } => {
-                    f.debug_struct(#var_str)
-                        #(#variant_fields)*
-                        .finish()
+                    let mut builder = f.debug_struct(#var_str);
+                    #(#field_chunks)*
+                    builder.finish()
                 }
             }
         }
@@ -170,14 +508,129 @@ fn debug_fmt_enum(variants: &[VariantOptions]) -> TokenStream2 {
     }
 }
 
+/// Adds a field to the debug builder, checking `skip_if` predicate first if present
+fn field_chunk(field: TokenStream2, name: Option<String>, opts: &FieldOptions) -> TokenStream2 {
+    let field_val = transform_field(field.clone(), opts);
+    let add_field = match name {
+        Some(name) => quote! { builder.field(#name, #field_val); },
+        None => quote! { builder.field(#field_val); },
+    };
+    match opts.skip_if {
+        Some(ref skip_if) => quote! {
+            if !#skip_if(#field) {
+                #add_field
+            }
+        },
+        None => add_field,
+    }
+}
+
 fn transform_field(field: TokenStream2, opts: &FieldOptions) -> TokenStream2 {
+    if let Some(ref with) = opts.with {
+        return quote! { &deboog::field::DebugWith(#field, #with) };
+    }
+    let map_mask = |mask: &Option<SpannedValue<Masking>>| mask.as_ref().and_then(|m| m.mask_type());
+    match (map_mask(&opts.mask_keys), map_mask(&opts.mask_values)) {
+        (Some(keys), Some(values)) => {
+            return quote! {
+                &deboog::field::MaskedEntries {
+                    value: #field,
+                    keys: #keys,
+                    values: #values,
+                }
+            };
+        }
+        (Some(mask_type), None) => {
+            return quote! {
+                &deboog::field::MaskedKeys {
+                    value: #field,
+                    mask_type: #mask_type,
+                }
+            };
+        }
+        (None, Some(mask_type)) => {
+            return quote! {
+                &deboog::field::MaskedValues {
+                    value: #field,
+                    mask_type: #mask_type,
+                }
+            };
+        }
+        (None, None) => {}
+    }
+    // Hidden values are never rendered, so encoding doesn't matter
+    if let (Some(bytes), false) = (opts.bytes, matches!(opts.mask, Some(Masking::Hidden))) {
+        let encoding = bytes.encoding();
+        let mask_type = match opts.mask.and_then(|mask| mask.mask_type()) {
+            Some(mask_type) => quote! { Some(#mask_type) },
+            None => quote! { None },
+        };
+        return quote! {
+            &deboog::field::MaskedBytes {
+                value: #field,
+                encoding: #encoding,
+                mask_type: #mask_type,
+            }
+        };
+    }
     match opts.mask {
         None => field,
         Some(mask_type) => match mask_type {
             Masking::All => quote! { &deboog::field::Masked::All(#field) },
             Masking::Pan => quote! { &deboog::field::Masked::Pan(#field) },
             Masking::PanSuffix => quote! { &deboog::field::Masked::PanSuffix(#field) },
-            Masking::Hidden => quote! { &deboog::field::Masked::Hidden(#field) },
+            Masking::IpSubnet => quote! { &deboog::field::Masked::IpSubnet(#field) },
+            Masking::IpPort => quote! { &deboog::field::Masked::IpPort(#field) },
+            Masking::Hash => quote! { &deboog::field::Masked::Hash(#field) },
+            Masking::Length => quote! { &deboog::field::Masked::Length(#field) },
+            Masking::LengthBucket => quote! { &deboog::field::Masked::LengthBucket(#field) },
+            Masking::Phone => quote! { &deboog::field::Masked::Phone(#field) },
+            Masking::PreserveFormat => quote! { &deboog::field::Masked::PreserveFormat(#field) },
+            Masking::Hidden => match opts.placeholder {
+                Some(ref placeholder) => {
+                    let placeholder = placeholder.as_str();
+                    quote! {
+                        &deboog::field::Masked::Placeholder {
+                            value: #field,
+                            placeholder: #placeholder,
+                        }
+                    }
+                }
+                None => quote! { &deboog::field::Masked::Hidden(#field) },
+            },
+            Masking::Card(params) => {
+                let brand = params.brand;
+                quote! {
+                    &deboog::field::Masked::Card {
+                        value: #field,
+                        brand: #brand,
+                    }
+                }
+            }
+            Masking::Email(params) => {
+                let keep_domain = params.keep_domain;
+                let fallback = params.fallback.mask_type();
+                quote! {
+                    &deboog::field::Masked::Email {
+                        value: #field,
+                        keep_domain: #keep_domain,
+                        fallback: &#fallback,
+                    }
+                }
+            }
+            Masking::Partial(params) => {
+                let keep_start = params.keep_start;
+                let keep_end = params.keep_end;
+                let mask_char = params.mask_char;
+                quote! {
+                    &deboog::field::Masked::Partial {
+                        value: #field,
+                        keep_start: #keep_start,
+                        keep_end: #keep_end,
+                        mask_char: #mask_char,
+                    }
+                }
+            }
         },
     }
 }
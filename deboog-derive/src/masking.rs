@@ -0,0 +1,238 @@
+use darling::{ast::NestedMeta, FromMeta};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::Meta;
+
+/// Masking strategy set by `#[deboog(mask = ...)]` field attribute
+#[derive(Clone, Copy)]
+pub(crate) enum Masking {
+    All,
+    Pan,
+    PanSuffix,
+    Card(CardParams),
+    Hidden,
+    Email(EmailParams),
+    Partial(PartialParams),
+    IpSubnet,
+    IpPort,
+    Hash,
+    Length,
+    LengthBucket,
+    Phone,
+    PreserveFormat,
+}
+
+/// Parameters for `#[deboog(mask(email(...)))]`
+#[derive(Clone, Copy, FromMeta)]
+pub(crate) struct EmailParams {
+    #[darling(default = "default_true")]
+    pub(crate) keep_domain: bool,
+    #[darling(default = "default_email_fallback", with = email_fallback)]
+    pub(crate) fallback: &'static Masking,
+}
+
+/// Parameters for `#[deboog(mask(card(...)))]`
+#[derive(Clone, Copy, FromMeta)]
+pub(crate) struct CardParams {
+    #[darling(default)]
+    pub(crate) brand: bool,
+}
+
+/// Parameters for `#[deboog(mask(keep_start = ..., keep_end = ..., char = ...))]`
+#[derive(Clone, Copy, FromMeta)]
+pub(crate) struct PartialParams {
+    #[darling(default)]
+    pub(crate) keep_start: usize,
+    #[darling(default)]
+    pub(crate) keep_end: usize,
+    #[darling(rename = "char", default = "default_mask_char")]
+    pub(crate) mask_char: char,
+}
+
+fn default_mask_char() -> char {
+    '*'
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_email_fallback() -> &'static Masking {
+    &Masking::All
+}
+
+/// Parses masking strategy for values that are not e-mail addresses
+fn email_fallback(meta: &Meta) -> darling::Result<&'static Masking> {
+    let name = String::from_meta(meta)?;
+    match Masking::NAMES.iter().find(|(n, _)| *n == name) {
+        Some((_, Masking::Hidden)) => Err(darling::Error::custom(
+            "`hidden` is not supported as e-mail fallback",
+        )
+        .with_span(meta)),
+        Some((_, mask)) => Ok(mask),
+        None => Err(Masking::unknown(&name).with_span(meta)),
+    }
+}
+
+impl Masking {
+    /// Names accepted by `#[deboog(mask = "...")]`
+    const NAMES: &'static [(&'static str, Masking)] = &[
+        ("all", Masking::All),
+        ("pan", Masking::Pan),
+        ("pan_suffix", Masking::PanSuffix),
+        ("card", Masking::Card(CardParams { brand: false })),
+        ("hidden", Masking::Hidden),
+        (
+            "email",
+            Masking::Email(EmailParams {
+                keep_domain: true,
+                fallback: &Masking::All,
+            }),
+        ),
+        ("ip_subnet", Masking::IpSubnet),
+        ("ip_port", Masking::IpPort),
+        ("hash", Masking::Hash),
+        ("length", Masking::Length),
+        ("length_bucket", Masking::LengthBucket),
+        ("phone", Masking::Phone),
+        ("preserve_format", Masking::PreserveFormat),
+    ];
+
+    /// Produces `MaskType` value for masking strategies that have one
+    pub(crate) fn mask_type(&self) -> Option<TokenStream> {
+        let mask_type = match self {
+            Masking::All => quote! { deboog::masking::MaskType::All },
+            Masking::Pan => quote! { deboog::masking::MaskType::Pan },
+            Masking::PanSuffix => quote! { deboog::masking::MaskType::PanSuffix },
+            Masking::IpSubnet => quote! { deboog::masking::MaskType::IpSubnet },
+            Masking::IpPort => quote! { deboog::masking::MaskType::IpPort },
+            Masking::Hash => quote! { deboog::masking::MaskType::Hash },
+            Masking::Length => quote! { deboog::masking::MaskType::Length },
+            Masking::LengthBucket => quote! { deboog::masking::MaskType::LengthBucket },
+            Masking::Phone => quote! { deboog::masking::MaskType::Phone },
+            Masking::PreserveFormat => quote! { deboog::masking::MaskType::PreserveFormat },
+            Masking::Hidden => return None,
+            Masking::Card(params) => {
+                let brand = params.brand;
+                quote! { deboog::masking::MaskType::Card { brand: #brand } }
+            }
+            Masking::Email(params) => {
+                let keep_domain = params.keep_domain;
+                let fallback = params.fallback.mask_type()?;
+                quote! {
+                    deboog::masking::MaskType::Email {
+                        keep_domain: #keep_domain,
+                        fallback: &#fallback,
+                    }
+                }
+            }
+            Masking::Partial(params) => {
+                let keep_start = params.keep_start;
+                let keep_end = params.keep_end;
+                let mask_char = params.mask_char;
+                quote! {
+                    deboog::masking::MaskType::Partial {
+                        keep_start: #keep_start,
+                        keep_end: #keep_end,
+                        mask_char: #mask_char,
+                    }
+                }
+            }
+        };
+        Some(mask_type)
+    }
+
+    fn unknown(value: &str) -> darling::Error {
+        let names: Vec<String> = Self::NAMES
+            .iter()
+            .map(|(name, _)| format!("`{}`", name))
+            .collect();
+        darling::Error::custom(format!(
+            "unknown mask type `{}`, expected one of: {}",
+            value,
+            names.join(", ")
+        ))
+    }
+}
+
+impl FromMeta for Masking {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Self::NAMES
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, mask)| *mask)
+            .ok_or_else(|| Self::unknown(value))
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        if let Some(NestedMeta::Meta(Meta::NameValue(_))) = items.first() {
+            return PartialParams::from_list(items).map(Masking::Partial);
+        }
+        let item = match items {
+            [item] => item,
+            _ => return Err(darling::Error::custom("expected a single mask type")),
+        };
+        match item {
+            NestedMeta::Meta(Meta::Path(path)) => {
+                let name = path.to_token_stream().to_string();
+                Self::from_string(&name).map_err(|e| e.with_span(path))
+            }
+            NestedMeta::Meta(Meta::List(list)) => {
+                let name = list.path.to_token_stream().to_string();
+                let params = NestedMeta::parse_meta_list(list.tokens.clone())?;
+                match name.as_str() {
+                    "email" => EmailParams::from_list(&params).map(Masking::Email),
+                    "card" => CardParams::from_list(&params).map(Masking::Card),
+                    _ => Err(Self::unknown(&name).with_span(&list.path)),
+                }
+            }
+            _ => Err(darling::Error::unexpected_type("literal").with_span(item)),
+        }
+    }
+}
+
+/// Byte buffer rendering set by `#[deboog(bytes = "...")]` field attribute
+#[derive(Clone, Copy)]
+pub(crate) enum BytesEncoding {
+    Hex,
+    Base64,
+    Length,
+}
+
+impl BytesEncoding {
+    /// Names accepted by `#[deboog(bytes = "...")]`
+    const NAMES: &'static [(&'static str, BytesEncoding)] = &[
+        ("hex", BytesEncoding::Hex),
+        ("base64", BytesEncoding::Base64),
+        ("length", BytesEncoding::Length),
+    ];
+
+    /// Produces `BytesEncoding` value for the library
+    pub(crate) fn encoding(&self) -> TokenStream {
+        match self {
+            BytesEncoding::Hex => quote! { deboog::masking::BytesEncoding::Hex },
+            BytesEncoding::Base64 => quote! { deboog::masking::BytesEncoding::Base64 },
+            BytesEncoding::Length => quote! { deboog::masking::BytesEncoding::Length },
+        }
+    }
+}
+
+impl FromMeta for BytesEncoding {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Self::NAMES
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, encoding)| *encoding)
+            .ok_or_else(|| {
+                let names: Vec<String> = Self::NAMES
+                    .iter()
+                    .map(|(name, _)| format!("`{}`", name))
+                    .collect();
+                darling::Error::custom(format!(
+                    "unknown bytes encoding `{}`, expected one of: {}",
+                    value,
+                    names.join(", ")
+                ))
+            })
+    }
+}
@@ -0,0 +1,66 @@
+use darling::FromMeta;
+use heck::{
+    ToKebabCase, ToLowerCamelCase, ToShoutyKebabCase, ToShoutySnakeCase, ToSnakeCase,
+    ToUpperCamelCase,
+};
+
+/// Case conversion set by `#[deboog(rename_all = "...")]` attribute
+#[derive(Clone, Copy)]
+pub(crate) enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    /// Names accepted by `#[deboog(rename_all = "...")]`
+    const NAMES: &'static [(&'static str, RenameRule)] = &[
+        ("lowercase", RenameRule::Lower),
+        ("UPPERCASE", RenameRule::Upper),
+        ("PascalCase", RenameRule::Pascal),
+        ("camelCase", RenameRule::Camel),
+        ("snake_case", RenameRule::Snake),
+        ("SCREAMING_SNAKE_CASE", RenameRule::ScreamingSnake),
+        ("kebab-case", RenameRule::Kebab),
+        ("SCREAMING-KEBAB-CASE", RenameRule::ScreamingKebab),
+    ];
+
+    /// Converts field or variant name according to the rule
+    pub(crate) fn apply(self, name: &str) -> String {
+        match self {
+            RenameRule::Lower => name.to_lowercase(),
+            RenameRule::Upper => name.to_uppercase(),
+            RenameRule::Pascal => name.to_upper_camel_case(),
+            RenameRule::Camel => name.to_lower_camel_case(),
+            RenameRule::Snake => name.to_snake_case(),
+            RenameRule::ScreamingSnake => name.to_shouty_snake_case(),
+            RenameRule::Kebab => name.to_kebab_case(),
+            RenameRule::ScreamingKebab => name.to_shouty_kebab_case(),
+        }
+    }
+}
+
+impl FromMeta for RenameRule {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Self::NAMES
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, rule)| *rule)
+            .ok_or_else(|| {
+                let names: Vec<String> = Self::NAMES
+                    .iter()
+                    .map(|(name, _)| format!("`{}`", name))
+                    .collect();
+                darling::Error::custom(format!(
+                    "unknown rename rule `{}`, expected one of: {}",
+                    value,
+                    names.join(", ")
+                ))
+            })
+    }
+}
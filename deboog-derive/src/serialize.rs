@@ -0,0 +1,291 @@
+use darling::ast::{Data, Style};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Ident, Index};
+
+use crate::{
+    add_trait_bounds, automatically_derived, transform_field, FieldOptions, Options, VariantOptions,
+};
+
+/// Generates `Serialize` impl that applies the same masking rules as `Debug` impl
+pub(crate) fn serialize_impl(opts: &Options) -> TokenStream2 {
+    let ident = &opts.ident;
+    let generics = add_trait_bounds(
+        opts,
+        quote! { deboog::serde::export::Serialize },
+        quote! { deboog::field::DeboogField + deboog::serde::SerializeMasked },
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body = serialize_body(&opts.name(), &opts.data);
+    let derived = automatically_derived(&opts.data);
+    quote! {
+        #derived
+        impl #impl_generics deboog::serde::export::Serialize for #ident #ty_generics #where_clause {
+            fn serialize<__S>(&self, __serializer: __S) -> std::result::Result<__S::Ok, __S::Error>
+            where
+                __S: deboog::serde::export::Serializer,
+            {
+                #body
+            }
+        }
+    }
+}
+
+fn serialize_body(ident_str: &str, data: &Data<VariantOptions, FieldOptions>) -> TokenStream2 {
+    match data {
+        Data::Enum(variants) => serialize_enum(ident_str, variants),
+        Data::Struct(fields) => match fields.style {
+            Style::Unit => quote! {
+                deboog::serde::export::Serializer::serialize_unit_struct(__serializer, #ident_str)
+            },
+            Style::Struct => {
+                let fields = fields.iter().map(|f| {
+                    let field = &f.ident;
+                    (quote! { &self.#field }, f)
+                });
+                serialize_struct(ident_str, fields.collect())
+            }
+            Style::Tuple => {
+                let fields = fields.iter().enumerate().map(|(i, f)| {
+                    let i = Index::from(i);
+                    (quote! { &self.#i }, f)
+                });
+                serialize_tuple_struct(ident_str, fields.collect())
+            }
+        },
+    }
+}
+
+fn serialize_struct(ident_str: &str, fields: Vec<(TokenStream2, &FieldOptions)>) -> TokenStream2 {
+    let len = serialized_len(&fields);
+    let field_chunks = struct_field_chunks(
+        &fields,
+        quote! { deboog::serde::export::ser::SerializeStruct },
+    );
+    quote! {
+        let mut __state = deboog::serde::export::Serializer::serialize_struct(
+            __serializer,
+            #ident_str,
+            #len,
+        )?;
+        #(#field_chunks)*
+        deboog::serde::export::ser::SerializeStruct::end(__state)
+    }
+}
+
+fn serialize_tuple_struct(
+    ident_str: &str,
+    fields: Vec<(TokenStream2, &FieldOptions)>,
+) -> TokenStream2 {
+    if let Some(value) = newtype_value(&fields) {
+        return quote! {
+            deboog::serde::export::Serializer::serialize_newtype_struct(
+                __serializer,
+                #ident_str,
+                #value,
+            )
+        };
+    }
+    let len = serialized_len(&fields);
+    let field_chunks = tuple_field_chunks(
+        &fields,
+        quote! { deboog::serde::export::ser::SerializeTupleStruct },
+    );
+    quote! {
+        let mut __state = deboog::serde::export::Serializer::serialize_tuple_struct(
+            __serializer,
+            #ident_str,
+            #len,
+        )?;
+        #(#field_chunks)*
+        deboog::serde::export::ser::SerializeTupleStruct::end(__state)
+    }
+}
+
+fn serialize_enum(ident_str: &str, variants: &[VariantOptions]) -> TokenStream2 {
+    let variant_chunks = variants.iter().enumerate().map(|(index, v)| {
+        let var = &v.ident;
+        let var_str = v.name();
+        let index = index as u32;
+        let binding = |i: usize| Ident::new(&format!("__self_{}", i), v.ident.span());
+
+        if v.fields.is_unit() {
+            quote! {
+                Self::#var => deboog::serde::export::Serializer::serialize_unit_variant(
+                    __serializer,
+                    #ident_str,
+                    #index,
+                    #var_str,
+                )
+            }
+        } else if v.fields.is_tuple() {
+            let patterns = v.fields.iter().enumerate().map(|(i, f)| {
+                if f.skip {
+                    Ident::new("_", v.ident.span())
+                } else {
+                    binding(i)
+                }
+            });
+            let fields: Vec<(TokenStream2, &FieldOptions)> = v
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, f)| {
+                    let field = binding(i);
+                    (quote! { #field }, f)
+                })
+                .collect();
+            let body = match newtype_value(&fields) {
+                Some(value) => quote! {
+                    deboog::serde::export::Serializer::serialize_newtype_variant(
+                        __serializer,
+                        #ident_str,
+                        #index,
+                        #var_str,
+                        #value,
+                    )
+                },
+                None => {
+                    let len = serialized_len(&fields);
+                    let field_chunks = tuple_field_chunks(
+                        &fields,
+                        quote! { deboog::serde::export::ser::SerializeTupleVariant },
+                    );
+                    quote! {
+                        let mut __state =
+                            deboog::serde::export::Serializer::serialize_tuple_variant(
+                                __serializer,
+                                #ident_str,
+                                #index,
+                                #var_str,
+                                #len,
+                            )?;
+                        #(#field_chunks)*
+                        deboog::serde::export::ser::SerializeTupleVariant::end(__state)
+                    }
+                }
+            };
+            quote! {
+                Self::#var(#(#patterns),*) => {
+                    #body
+                }
+            }
+        } else {
+            let patterns = v
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| !f.skip)
+                .map(|(i, f)| {
+                    let field = &f.ident;
+                    let bound = binding(i);
+                    quote! { #field: #bound }
+                });
+            let fields: Vec<(TokenStream2, &FieldOptions)> = v
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, f)| {
+                    let field = binding(i);
+                    (quote! { #field }, f)
+                })
+                .collect();
+            let len = serialized_len(&fields);
+            let field_chunks = struct_field_chunks(
+                &fields,
+                quote! { deboog::serde::export::ser::SerializeStructVariant },
+            );
+            quote! {
+                Self::#var { #(#patterns,)* .. } => {
+                    let mut __state = deboog::serde::export::Serializer::serialize_struct_variant(
+                        __serializer,
+                        #ident_str,
+                        #index,
+                        #var_str,
+                        #len,
+                    )?;
+                    #(#field_chunks)*
+                    deboog::serde::export::ser::SerializeStructVariant::end(__state)
+                }
+            }
+        }
+    });
+    quote! {
+        match self {
+            #(#variant_chunks),*
+        }
+    }
+}
+
+/// Returns serialized value of the only field of a newtype struct or variant
+fn newtype_value(fields: &[(TokenStream2, &FieldOptions)]) -> Option<TokenStream2> {
+    match fields {
+        [(field, f)] if !f.skip && f.skip_if.is_none() => Some(transform_field(field.clone(), f)),
+        _ => None,
+    }
+}
+
+/// Number of serialized fields, taking `skip` and `skip_if` attributes into account
+fn serialized_len(fields: &[(TokenStream2, &FieldOptions)]) -> TokenStream2 {
+    let counts = fields
+        .iter()
+        .filter(|(_, f)| !f.skip)
+        .map(|(field, f)| match f.skip_if {
+            Some(ref skip_if) => quote! { usize::from(!#skip_if(#field)) },
+            None => quote! { 1 },
+        });
+    quote! { 0 #(+ #counts)* }
+}
+
+/// Adds named fields of a struct or variant, checking `skip_if` predicate first if present
+fn struct_field_chunks(
+    fields: &[(TokenStream2, &FieldOptions)],
+    trait_path: TokenStream2,
+) -> Vec<TokenStream2> {
+    fields
+        .iter()
+        .filter(|(_, f)| !f.skip)
+        .map(|(field, f)| {
+            let name = f.name();
+            let value = transform_field(field.clone(), f);
+            let add_field = quote! {
+                #trait_path::serialize_field(&mut __state, #name, #value)?;
+            };
+            match f.skip_if {
+                Some(ref skip_if) => quote! {
+                    if !#skip_if(#field) {
+                        #add_field
+                    } else {
+                        #trait_path::skip_field(&mut __state, #name)?;
+                    }
+                },
+                None => add_field,
+            }
+        })
+        .collect()
+}
+
+/// Adds fields of a tuple struct or variant, checking `skip_if` predicate first if present
+fn tuple_field_chunks(
+    fields: &[(TokenStream2, &FieldOptions)],
+    trait_path: TokenStream2,
+) -> Vec<TokenStream2> {
+    fields
+        .iter()
+        .filter(|(_, f)| !f.skip)
+        .map(|(field, f)| {
+            let value = transform_field(field.clone(), f);
+            let add_field = quote! {
+                #trait_path::serialize_field(&mut __state, #value)?;
+            };
+            match f.skip_if {
+                Some(ref skip_if) => quote! {
+                    if !#skip_if(#field) {
+                        #add_field
+                    }
+                },
+                None => add_field,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,286 @@
+use darling::ast::{Data, Style};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Ident, Index};
+
+use crate::{
+    add_trait_bounds, automatically_derived, transform_field, FieldOptions, Options, VariantOptions,
+};
+
+/// Generates `Valuable` impl that applies the same masking rules as `Debug` impl
+pub(crate) fn valuable_impl(opts: &Options) -> TokenStream2 {
+    let ident = &opts.ident;
+    let ident_str = opts.name();
+    let generics = add_trait_bounds(
+        opts,
+        quote! { deboog::valuable::export::Valuable },
+        quote! { deboog::field::DeboogField + deboog::valuable::ValuableMasked },
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let derived = automatically_derived(&opts.data);
+    let (definitions, as_value, visit_body, (def_trait, def_methods)) = match opts.data {
+        Data::Enum(ref variants) => {
+            let (definitions, visit_body, def_impl) = enum_impl(&ident_str, variants);
+            let as_value = quote! { deboog::valuable::export::Value::Enumerable(self) };
+            (definitions, as_value, visit_body, def_impl)
+        }
+        Data::Struct(ref fields) => {
+            let fields_ident = format_ident!("__FIELDS");
+            let bound: Vec<(TokenStream2, &FieldOptions)> = fields
+                .iter()
+                .enumerate()
+                .map(|(i, f)| match f.ident {
+                    Some(ref field) => (quote! { &self.#field }, f),
+                    None => {
+                        let i = Index::from(i);
+                        (quote! { &self.#i }, f)
+                    }
+                })
+                .collect();
+            let (fields_def, definitions) = fields_definition(&fields_ident, fields.style, &bound);
+            let visit_body = visit_fields(&fields_ident, fields.style, &bound);
+            // Set of recorded fields depends on the value if some of them can be skipped
+            let def = if bound.iter().any(|(_, f)| !f.skip && f.skip_if.is_some()) {
+                quote! { new_dynamic }
+            } else {
+                quote! { new_static }
+            };
+            let def_methods = quote! {
+                fn definition(&self) -> deboog::valuable::export::StructDef<'_> {
+                    deboog::valuable::export::StructDef::#def(#ident_str, #fields_def)
+                }
+            };
+            let def_impl = (quote! { deboog::valuable::export::Structable }, def_methods);
+            let as_value = quote! { deboog::valuable::export::Value::Structable(self) };
+            (definitions, as_value, visit_body, def_impl)
+        }
+    };
+    quote! {
+        const _: () = {
+            #definitions
+
+            #derived
+            impl #impl_generics deboog::valuable::export::Valuable for #ident #ty_generics #where_clause {
+                fn as_value(&self) -> deboog::valuable::export::Value<'_> {
+                    #as_value
+                }
+
+                fn visit(&self, __visit: &mut dyn deboog::valuable::export::Visit) {
+                    #visit_body
+                }
+            }
+
+            #derived
+            impl #impl_generics #def_trait for #ident #ty_generics #where_clause {
+                #def_methods
+            }
+        };
+    }
+}
+
+fn enum_impl(
+    ident_str: &str,
+    variants: &[VariantOptions],
+) -> (TokenStream2, TokenStream2, (TokenStream2, TokenStream2)) {
+    let mut definitions = Vec::new();
+    let mut variant_defs = Vec::new();
+    let mut variant_arms = Vec::new();
+    let mut visit_arms = Vec::new();
+    for (index, v) in variants.iter().enumerate() {
+        let var = &v.ident;
+        let var_str = v.name();
+        let fields_ident = format_ident!("__FIELDS_{}", index);
+        let binding = |i: usize| Ident::new(&format!("__self_{}", i), v.ident.span());
+        let bound: Vec<(TokenStream2, &FieldOptions)> = v
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                let field = binding(i);
+                (quote! { #field }, f)
+            })
+            .collect();
+        let (fields_def, fields_const) = fields_definition(&fields_ident, v.fields.style, &bound);
+        definitions.push(fields_const);
+        let variant_def = quote! {
+            deboog::valuable::export::VariantDef::new(#var_str, #fields_def)
+        };
+        // Variants with fields that can be skipped are not a part of static definition
+        if bound.iter().any(|(_, f)| !f.skip && f.skip_if.is_some()) {
+            variant_arms.push(quote! {
+                Self::#var { .. } => deboog::valuable::export::Variant::Dynamic(#variant_def)
+            });
+        } else {
+            let index = variant_defs.len();
+            variant_arms.push(quote! {
+                Self::#var { .. } => deboog::valuable::export::Variant::Static(&__VARIANTS[#index])
+            });
+            variant_defs.push(variant_def);
+        }
+        let visit_body = visit_fields(&fields_ident, v.fields.style, &bound);
+        let pattern = match v.fields.style {
+            Style::Unit => quote! { Self::#var },
+            Style::Tuple => {
+                let patterns = v.fields.iter().enumerate().map(|(i, f)| {
+                    if f.skip {
+                        Ident::new("_", v.ident.span())
+                    } else {
+                        binding(i)
+                    }
+                });
+                quote! { Self::#var(#(#patterns),*) }
+            }
+            Style::Struct => {
+                let patterns =
+                    v.fields
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, f)| !f.skip)
+                        .map(|(i, f)| {
+                            let field = &f.ident;
+                            let bound = binding(i);
+                            quote! { #field: #bound }
+                        });
+                quote! { Self::#var { #(#patterns,)* .. } }
+            }
+        };
+        visit_arms.push(quote! {
+            #pattern => {
+                #visit_body
+            }
+        });
+    }
+    let definitions = quote! {
+        #(#definitions)*
+        const __VARIANTS: &[deboog::valuable::export::VariantDef<'static>] = &[#(#variant_defs),*];
+    };
+    let visit_body = quote! {
+        match self {
+            #(#visit_arms),*
+        }
+    };
+    let def = if variant_defs.len() < variants.len() {
+        quote! { new_dynamic }
+    } else {
+        quote! { new_static }
+    };
+    let def_methods = quote! {
+        fn definition(&self) -> deboog::valuable::export::EnumDef<'_> {
+            deboog::valuable::export::EnumDef::#def(#ident_str, __VARIANTS)
+        }
+
+        fn variant(&self) -> deboog::valuable::export::Variant<'_> {
+            match self {
+                #(#variant_arms),*
+            }
+        }
+    };
+    let def_impl = (quote! { deboog::valuable::export::Enumerable }, def_methods);
+    (definitions, visit_body, def_impl)
+}
+
+/// Returns `Fields` definition of a struct or variant, and constant with names of its fields
+fn fields_definition(
+    fields_ident: &Ident,
+    style: Style,
+    fields: &[(TokenStream2, &FieldOptions)],
+) -> (TokenStream2, TokenStream2) {
+    let recorded = fields.iter().filter(|(_, f)| !f.skip);
+    match style {
+        Style::Struct => {
+            let names = recorded.map(|(_, f)| f.name());
+            let fields_const = quote! {
+                const #fields_ident: &[deboog::valuable::export::NamedField<'static>] = &[
+                    #(deboog::valuable::export::NamedField::new(#names)),*
+                ];
+            };
+            (
+                quote! { deboog::valuable::export::Fields::Named(#fields_ident) },
+                fields_const,
+            )
+        }
+        Style::Tuple | Style::Unit => {
+            let len = recorded.count();
+            (
+                quote! { deboog::valuable::export::Fields::Unnamed(#len) },
+                quote! {},
+            )
+        }
+    }
+}
+
+/// Visits recorded fields of a struct or variant, checking `skip_if` predicate first if present
+fn visit_fields(
+    fields_ident: &Ident,
+    style: Style,
+    fields: &[(TokenStream2, &FieldOptions)],
+) -> TokenStream2 {
+    let pushes = fields
+        .iter()
+        .filter(|(_, f)| !f.skip)
+        .enumerate()
+        .map(|(index, (field, f))| {
+            let value = field_value(field.clone(), f);
+            let push = match style {
+                Style::Struct => quote! {
+                    __names.push(#fields_ident[#index]);
+                    __values.push(#value);
+                },
+                Style::Tuple | Style::Unit => quote! {
+                    __values.push(#value);
+                },
+            };
+            match f.skip_if {
+                Some(ref skip_if) => quote! {
+                    if !#skip_if(#field) {
+                        #push
+                    }
+                },
+                None => push,
+            }
+        });
+    let visit = match style {
+        Style::Struct => quote! {
+            __visit.visit_named_fields(&deboog::valuable::export::NamedValues::new(
+                &__names,
+                &__values,
+            ));
+        },
+        Style::Tuple | Style::Unit => quote! {
+            __visit.visit_unnamed_fields(&__values);
+        },
+    };
+    let names = match style {
+        Style::Struct => quote! {
+            #[allow(unused_mut)]
+            let mut __names: std::vec::Vec<deboog::valuable::export::NamedField<'static>> =
+                std::vec::Vec::new();
+        },
+        Style::Tuple | Style::Unit => quote! {},
+    };
+    quote! {
+        #names
+        #[allow(unused_mut)]
+        let mut __values: std::vec::Vec<deboog::valuable::MaskedValue<'_>> = std::vec::Vec::new();
+        #(#pushes)*
+        let __values: std::vec::Vec<deboog::valuable::export::Value<'_>> = __values
+            .iter()
+            .map(deboog::valuable::export::Valuable::as_value)
+            .collect();
+        #visit
+    }
+}
+
+/// Returns recorded value of a field, masked if field has any masking attributes
+fn field_value(field: TokenStream2, opts: &FieldOptions) -> TokenStream2 {
+    if opts.is_masked() || opts.with.is_some() || opts.bytes.is_some() {
+        let value = transform_field(field, opts);
+        quote! { deboog::valuable::MaskedField::to_masked_value(#value) }
+    } else {
+        quote! {
+            deboog::valuable::MaskedValue::Plain(
+                deboog::valuable::export::Valuable::as_value(#field)
+            )
+        }
+    }
+}
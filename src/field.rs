@@ -1,8 +1,22 @@
-use std::fmt::{self, Debug, Formatter};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+    ffi::{CStr, CString, OsStr, OsString},
+    fmt::{self, Debug, Display, Formatter, Write},
+    num::Wrapping,
+    ops::Deref,
+    path::{Path, PathBuf},
+    pin::Pin,
+    rc::Rc,
+    sync::{Arc, Mutex, RwLock, TryLockError},
+};
 
-use crate::masking::*;
+use crate::{masking::*, policy};
 
 /// Wrapper object to specify type of masking
+#[non_exhaustive]
 pub enum Masked<'a, T: DeboogField> {
     /// Replaces all characters with `*`
     All(&'a T),
@@ -12,21 +26,327 @@ pub enum Masked<'a, T: DeboogField> {
     Pan(&'a T),
     /// Replaces all but last four characters with a single `*` symbol
     PanSuffix(&'a T),
+    /// Masks valid card number, leaving BIN and last four digits, or the brand and last four
+    /// digits
+    Card {
+        /// Value to mask
+        value: &'a T,
+        /// Show card brand and last four digits only
+        brand: bool,
+    },
     /// Replaces all characters with a fixed string
     Hidden(&'a T),
+    /// Same as [`Masked::Hidden`], but uses a custom replacement string
+    Placeholder {
+        /// Value to mask
+        value: &'a T,
+        /// Replacement string
+        placeholder: &'static str,
+    },
+    /// Masks e-mail address, leaving first character of the local part
+    Email {
+        /// Value to mask
+        value: &'a T,
+        /// Leave domain part unmasked
+        keep_domain: bool,
+        /// Mask type for values that are not e-mail addresses
+        fallback: &'static MaskType,
+    },
+    /// Replaces all characters with `mask_char`, except for configured number of leading
+    /// and trailing characters
+    Partial {
+        /// Value to mask
+        value: &'a T,
+        /// Number of leading characters to leave unmasked
+        keep_start: usize,
+        /// Number of trailing characters to leave unmasked
+        keep_end: usize,
+        /// Character to use for masking
+        mask_char: char,
+    },
+    /// Masks host part of an IP address, leaving /24 prefix for IPv4 and /48 prefix for IPv6
+    IpSubnet(&'a T),
+    /// Masks IP address completely, leaving port of a socket address unmasked
+    IpPort(&'a T),
+    /// Replaces value with a short keyed fingerprint, e.g. `sha256:3fa9c1d2e4b5…`
+    Hash(&'a T),
+    /// Replaces value with its length, e.g. `<redacted 16 chars>`
+    Length(&'a T),
+    /// Replaces value with a rounded range of its length, e.g. `<redacted ~10-20 chars>`
+    LengthBucket(&'a T),
+    /// Masks phone number, leaving leading `+` with country code and last digits
+    Phone(&'a T),
+    /// Replaces digits with `#` and letters with `x` or `X`, keeping other characters
+    PreserveFormat(&'a T),
+}
+
+impl<'a, T: DeboogField> Masked<'a, T> {
+    /// Value to mask
+    pub(crate) fn value(&self) -> &'a T {
+        match *self {
+            Masked::All(value)
+            | Masked::Pan(value)
+            | Masked::PanSuffix(value)
+            | Masked::Card { value, .. }
+            | Masked::Hidden(value)
+            | Masked::Placeholder { value, .. }
+            | Masked::Email { value, .. }
+            | Masked::Partial { value, .. }
+            | Masked::IpSubnet(value)
+            | Masked::IpPort(value)
+            | Masked::Length(value)
+            | Masked::LengthBucket(value)
+            | Masked::Phone(value)
+            | Masked::Hash(value)
+            | Masked::PreserveFormat(value) => value,
+        }
+    }
+
+    /// Mask type to apply to the value, or `None` if the value is replaced completely
+    pub(crate) fn mask_type(&self) -> Option<MaskType> {
+        Some(match *self {
+            Masked::All(_) => MaskType::All,
+            Masked::Pan(_) => MaskType::Pan,
+            Masked::PanSuffix(_) => MaskType::PanSuffix,
+            Masked::Card { brand, .. } => MaskType::Card { brand },
+            Masked::Hidden(_) | Masked::Placeholder { .. } => return None,
+            Masked::Email {
+                keep_domain,
+                fallback,
+                ..
+            } => MaskType::Email {
+                keep_domain,
+                fallback,
+            },
+            Masked::Partial {
+                keep_start,
+                keep_end,
+                mask_char,
+                ..
+            } => MaskType::Partial {
+                keep_start,
+                keep_end,
+                mask_char,
+            },
+            Masked::IpSubnet(_) => MaskType::IpSubnet,
+            Masked::IpPort(_) => MaskType::IpPort,
+            Masked::Hash(_) => MaskType::Hash,
+            Masked::Length(_) => MaskType::Length,
+            Masked::LengthBucket(_) => MaskType::LengthBucket,
+            Masked::Phone(_) => MaskType::Phone,
+            Masked::PreserveFormat(_) => MaskType::PreserveFormat,
+        })
+    }
+
+    /// Replacement string for values that are replaced completely
+    pub(crate) fn placeholder(&self) -> &'static str {
+        match *self {
+            Masked::Placeholder { placeholder, .. } => placeholder,
+            _ => HIDE_STR,
+        }
+    }
 }
 
 impl<'a, T: DeboogField> Debug for Masked<'a, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match self {
-            Masked::All(value) => value.fmt_masked(f, MaskType::All),
-            Masked::Pan(value) => value.fmt_masked(f, MaskType::Pan),
-            Masked::PanSuffix(value) => value.fmt_masked(f, MaskType::PanSuffix),
-            Masked::Hidden(_) => write!(f, "{}", HIDE_STR),
+        match self.mask_type() {
+            Some(mask_type) => self.value().fmt_masked(f, mask_type),
+            None if policy::reveals() => self.value().fmt_masked(f, MaskType::All),
+            None => f.write_str(self.placeholder()),
+        }
+    }
+}
+
+/// Wrapper object to format a value using a custom function
+///
+/// Used by `#[deboog(with = "...")]` attribute.
+pub struct DebugWith<'a, T: ?Sized>(
+    /// Value to format
+    pub &'a T,
+    /// Formatting function
+    pub fn(&T, &mut Formatter<'_>) -> fmt::Result,
+);
+
+impl<'a, T: ?Sized> Debug for DebugWith<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.1)(self.0, f)
+    }
+}
+
+impl<'a, T: ?Sized> Display for DebugWith<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.1)(self.0, f)
+    }
+}
+
+/// Wrapper object to mask [`Display`] output of a value
+///
+/// Used by `DeboogDisplay` derive for fields referenced in the display template.
+pub struct DisplayMasked<'a, T: ?Sized> {
+    /// Value to mask
+    pub value: &'a T,
+    /// Masking for the displayed string, replaces it with `placeholder` if not set
+    pub mask_type: Option<MaskType>,
+    /// Replacement string for hidden values
+    pub placeholder: &'static str,
+}
+
+impl<'a, T: Display + ?Sized> Display for DisplayMasked<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if policy::reveals() {
+            return self.value.fmt(f);
+        }
+        match self.mask_type {
+            Some(mask_type) => f.pad(&mask(&self.value.to_string(), mask_type)),
+            None => f.pad(self.placeholder),
         }
     }
 }
 
+/// Wrapper object to replace [`Display`] output of a hidden value
+///
+/// Used by `DeboogDisplay` derive for hidden fields referenced in the display template. Unlike
+/// [`DisplayMasked`], doesn't require the value to implement [`Display`]: revealed values are
+/// written the same way as in `Debug` output of `Deboog` derive.
+pub struct DisplayHidden<'a, T: ?Sized> {
+    /// Value to hide
+    pub value: &'a T,
+    /// Replacement string
+    pub placeholder: &'static str,
+}
+
+impl<'a, T: DeboogField + ?Sized> Display for DisplayHidden<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if policy::reveals() {
+            return self.value.fmt_masked(f, MaskType::All);
+        }
+        f.pad(self.placeholder)
+    }
+}
+
+/// Wrapper object to mask keys of a map, leaving values as is
+///
+/// Used by `#[deboog(mask_keys = "...")]` attribute.
+pub struct MaskedKeys<'a, T: DeboogMap> {
+    /// Map to mask
+    pub value: &'a T,
+    /// Masking for keys
+    pub mask_type: MaskType,
+}
+
+impl<'a, T: DeboogMap> Debug for MaskedKeys<'a, T>
+where
+    T::Key: DeboogField,
+    T::Value: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.value
+                    .map_entries()
+                    .map(|(key, value)| (AlwaysMasked(key, self.mask_type), value)),
+            )
+            .finish()
+    }
+}
+
+/// Wrapper object to mask values of a map, leaving keys as is
+///
+/// Used by `#[deboog(mask_values = "...")]` attribute.
+pub struct MaskedValues<'a, T: DeboogMap> {
+    /// Map to mask
+    pub value: &'a T,
+    /// Masking for values
+    pub mask_type: MaskType,
+}
+
+impl<'a, T: DeboogMap> Debug for MaskedValues<'a, T>
+where
+    T::Key: Debug,
+    T::Value: DeboogField,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.value
+                    .map_entries()
+                    .map(|(key, value)| (key, AlwaysMasked(value, self.mask_type))),
+            )
+            .finish()
+    }
+}
+
+/// Wrapper object to mask keys and values of a map separately
+///
+/// Used by `#[deboog(mask_keys = "...", mask_values = "...")]` attributes.
+pub struct MaskedEntries<'a, T: DeboogMap> {
+    /// Map to mask
+    pub value: &'a T,
+    /// Masking for keys
+    pub keys: MaskType,
+    /// Masking for values
+    pub values: MaskType,
+}
+
+impl<'a, T: DeboogMap> Debug for MaskedEntries<'a, T>
+where
+    T::Key: DeboogField,
+    T::Value: DeboogField,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.value.map_entries().map(|(key, value)| {
+                (
+                    AlwaysMasked(key, self.keys),
+                    AlwaysMasked(value, self.values),
+                )
+            }))
+            .finish()
+    }
+}
+
+/// Wrapper object to render and optionally mask byte buffers
+///
+/// Used by `#[deboog(bytes = "...")]` attribute.
+pub struct MaskedBytes<'a, T: AsRef<[u8]> + ?Sized> {
+    /// Byte buffer to render
+    pub value: &'a T,
+    /// Rendering of the buffer
+    pub encoding: BytesEncoding,
+    /// Masking for the rendered string, if any
+    pub mask_type: Option<MaskType>,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Debug for MaskedBytes<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let bytes = self.value.as_ref();
+        let encoded = match self.encoding {
+            BytesEncoding::Hex => encode_hex(bytes),
+            BytesEncoding::Base64 => encode_base64(bytes),
+            BytesEncoding::Length => return write!(f, "[u8; {} bytes]", bytes.len()),
+        };
+        match self.mask_type {
+            Some(mask_type) => write!(f, "{}", mask(&encoded, mask_type)),
+            None => write!(f, "{}", encoded),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Display for MaskedBytes<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Debug adapter that always masks the value
+struct AlwaysMasked<'a, T: ?Sized>(&'a T, MaskType);
+
+impl<'a, T: DeboogField + ?Sized> Debug for AlwaysMasked<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_masked(f, self.1)
+    }
+}
+
 /// Trait used to produce masked values
 pub trait DeboogField {
     /// Writes masked debug value
@@ -36,99 +356,300 @@ pub trait DeboogField {
     }
 }
 
-impl DeboogField for String {
-    fn fmt_masked(&self, f: &mut std::fmt::Formatter<'_>, mask_type: MaskType) -> std::fmt::Result {
-        let masked = mask(self, mask_type);
-        write!(f, "\"{}\"", masked)
-    }
+/// Trait used to access entries of maps with separately masked keys and values
+pub trait DeboogMap {
+    /// Type of map keys
+    type Key;
+    /// Type of map values
+    type Value;
+
+    /// Returns iterator over map entries, in the same order as [`Debug`] output
+    fn map_entries(&self) -> Box<dyn Iterator<Item = (&Self::Key, &Self::Value)> + '_>;
 }
 
-impl DeboogField for &str {
-    fn fmt_masked(&self, f: &mut std::fmt::Formatter<'_>, mask_type: MaskType) -> std::fmt::Result {
-        let masked = mask(self, mask_type);
-        write!(f, "\"{}\"", masked)
-    }
+macro_rules! str_impl {
+    ($t:ty, $value:ident => $as_str:expr, $quote:literal) => {
+        impl DeboogField for $t {
+            fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+                let $value = self;
+                let masked = mask(&$as_str, mask_type);
+                write!(f, "{}{}{}", $quote, masked, $quote)
+            }
+        }
+    };
 }
 
-impl<T: DeboogField> DeboogField for &T {
+impl<T: DeboogField + ?Sized> DeboogField for &T {
     fn fmt_masked(&self, f: &mut std::fmt::Formatter<'_>, mask_type: MaskType) -> std::fmt::Result {
         (*self).fmt_masked(f, mask_type)
     }
 }
 
 impl<T: DeboogField> DeboogField for Option<T> {
-    fn fmt_masked(&self, f: &mut std::fmt::Formatter<'_>, mask_type: MaskType) -> std::fmt::Result {
+    fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+        match self {
+            Some(ref value) => f
+                .debug_tuple("Some")
+                .field(&AlwaysMasked(value, mask_type))
+                .finish(),
+            None => f.write_str("None"),
+        }
+    }
+}
+
+impl<T: DeboogField, E: DeboogField> DeboogField for Result<T, E> {
+    fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
         match self {
-            Some(ref value) => {
-                write!(f, "Some(")?;
-                value.fmt_masked(f, mask_type)?;
-                write!(f, ")")
+            Ok(ref value) => f
+                .debug_tuple("Ok")
+                .field(&AlwaysMasked(value, mask_type))
+                .finish(),
+            Err(ref error) => f
+                .debug_tuple("Err")
+                .field(&AlwaysMasked(error, mask_type))
+                .finish(),
+        }
+    }
+}
+
+macro_rules! deref_impl {
+    ($t:ident) => {
+        impl<T: DeboogField + ?Sized> DeboogField for $t<T> {
+            fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+                (**self).fmt_masked(f, mask_type)
             }
-            None => write!(f, "None"),
         }
+    };
+}
+
+impl<'a, T: DeboogField + ToOwned + ?Sized> DeboogField for Cow<'a, T> {
+    fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+        (**self).fmt_masked(f, mask_type)
     }
 }
 
-impl<T: DeboogField> DeboogField for Vec<T> {
-    fn fmt_masked(&self, f: &mut std::fmt::Formatter<'_>, mask_type: MaskType) -> std::fmt::Result {
-        let mut first = true;
-        write!(f, "[")?;
-        for item in self {
-            if first {
-                first = false;
-            } else {
-                write!(f, ", ")?;
+impl<P: Deref> DeboogField for Pin<P>
+where
+    P::Target: DeboogField,
+{
+    fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+        (**self).fmt_masked(f, mask_type)
+    }
+}
+
+impl<T: DeboogField> DeboogField for Wrapping<T> {
+    fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+        self.0.fmt_masked(f, mask_type)
+    }
+}
+
+impl<T: DeboogField> DeboogField for Reverse<T> {
+    fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+        f.debug_tuple("Reverse")
+            .field(&AlwaysMasked(&self.0, mask_type))
+            .finish()
+    }
+}
+
+/// Placeholder for values that can't be accessed without blocking
+struct Unavailable(&'static str);
+
+impl Debug for Unavailable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl<T: DeboogField + ?Sized> DeboogField for RefCell<T> {
+    fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+        let mut d = f.debug_struct("RefCell");
+        match self.try_borrow() {
+            Ok(borrow) => d.field("value", &AlwaysMasked(&*borrow, mask_type)),
+            Err(_) => d.field("value", &Unavailable("<borrowed>")),
+        };
+        d.finish()
+    }
+}
+
+impl<T: DeboogField + ?Sized> DeboogField for Mutex<T> {
+    fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+        let mut d = f.debug_struct("Mutex");
+        match self.try_lock() {
+            Ok(guard) => d.field("data", &AlwaysMasked(&*guard, mask_type)),
+            Err(TryLockError::Poisoned(err)) => {
+                d.field("data", &AlwaysMasked(&**err.get_ref(), mask_type))
+            }
+            Err(TryLockError::WouldBlock) => d.field("data", &Unavailable("<locked>")),
+        };
+        d.field("poisoned", &self.is_poisoned());
+        d.finish_non_exhaustive()
+    }
+}
+
+impl<T: DeboogField + ?Sized> DeboogField for RwLock<T> {
+    fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+        let mut d = f.debug_struct("RwLock");
+        match self.try_read() {
+            Ok(guard) => d.field("data", &AlwaysMasked(&*guard, mask_type)),
+            Err(TryLockError::Poisoned(err)) => {
+                d.field("data", &AlwaysMasked(&**err.get_ref(), mask_type))
+            }
+            Err(TryLockError::WouldBlock) => d.field("data", &Unavailable("<locked>")),
+        };
+        d.field("poisoned", &self.is_poisoned());
+        d.finish_non_exhaustive()
+    }
+}
+
+impl<T: DeboogField> DeboogField for [T] {
+    fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter().map(|item| AlwaysMasked(item, mask_type)))
+            .finish()
+    }
+}
+
+impl<T: DeboogField, const N: usize> DeboogField for [T; N] {
+    fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+        self[..].fmt_masked(f, mask_type)
+    }
+}
+
+macro_rules! list_impl {
+    ($t:ident) => {
+        impl<T: DeboogField> DeboogField for $t<T> {
+            fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+                f.debug_list()
+                    .entries(self.iter().map(|item| AlwaysMasked(item, mask_type)))
+                    .finish()
+            }
+        }
+    };
+}
+
+macro_rules! set_impl {
+    ($t:ident $(, $s:ident)?) => {
+        impl<T: DeboogField $(, $s)?> DeboogField for $t<T $(, $s)?> {
+            fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+                f.debug_set()
+                    .entries(self.iter().map(|item| AlwaysMasked(item, mask_type)))
+                    .finish()
+            }
+        }
+    };
+}
+
+macro_rules! map_impl {
+    ($t:ident $(, $s:ident)?) => {
+        impl<K: DeboogField, V: DeboogField $(, $s)?> DeboogField for $t<K, V $(, $s)?> {
+            fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+                f.debug_map()
+                    .entries(self.iter().map(|(key, value)| {
+                        (AlwaysMasked(key, mask_type), AlwaysMasked(value, mask_type))
+                    }))
+                    .finish()
             }
-            item.fmt_masked(f, mask_type)?;
         }
-        write!(f, "]")
+
+        impl<K, V $(, $s)?> DeboogMap for $t<K, V $(, $s)?> {
+            type Key = K;
+            type Value = V;
+
+            fn map_entries(&self) -> Box<dyn Iterator<Item = (&K, &V)> + '_> {
+                Box::new(self.iter())
+            }
+        }
+    };
+}
+
+macro_rules! tuple_impl {
+    ($len:literal => $($name:ident)+) => {
+        impl<$($name: DeboogField),+> DeboogField for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn fmt_masked(&self, f: &mut Formatter<'_>, mask_type: MaskType) -> fmt::Result {
+                let ($(ref $name,)+) = *self;
+                f.debug_tuple("")
+                    $(.field(&AlwaysMasked($name, mask_type)))+
+                    .finish()
+            }
+        }
+    };
+}
+
+/// Writes masked string representation of a value, honoring formatter options
+///
+/// Sign, precision and zero padding are applied before masking, so that masked output has
+/// the same length as the plain one. Width, fill and alignment are applied to the masked string.
+/// Numeric values are aligned to the right by default and text values to the left, same as in std.
+/// Hexadecimal debug flags (`{:x?}`) are not exposed by [`Formatter`] and are ignored.
+fn fmt_display_masked<T: fmt::Display + ?Sized>(
+    value: &T,
+    f: &mut Formatter<'_>,
+    mask_type: MaskType,
+    numeric: bool,
+) -> fmt::Result {
+    let mut plain = match (f.sign_plus(), f.precision()) {
+        (false, None) => format!("{}", value),
+        (false, Some(precision)) => format!("{:.1$}", value, precision),
+        (true, None) => format!("{:+}", value),
+        (true, Some(precision)) => format!("{:+.1$}", value, precision),
+    };
+    let width = f.width().unwrap_or(0);
+    let len = plain.chars().count();
+    if numeric && f.sign_aware_zero_pad() && width > len {
+        let sign_len = if plain.starts_with(['+', '-']) { 1 } else { 0 };
+        plain.insert_str(sign_len, &"0".repeat(width - len));
     }
+    let masked = mask(&plain, mask_type);
+    let padding = width.saturating_sub(masked.chars().count());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) => (padding, 0),
+        None if numeric => (padding, 0),
+        None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(&masked)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
 }
 
 macro_rules! display_impl {
     ($t:ty) => {
+        display_impl!($t, true);
+    };
+    ($t:ty, $numeric:expr) => {
         impl DeboogField for $t {
             fn fmt_masked(
                 &self,
                 f: &mut std::fmt::Formatter<'_>,
                 mask_type: MaskType,
             ) -> std::fmt::Result {
-                let plain = format!("{}", self);
-                write!(f, "{}", mask(&plain, mask_type))
+                if policy::reveals() {
+                    return Debug::fmt(self, f);
+                }
+                fmt_display_masked(self, f, mask_type, $numeric)
             }
         }
     };
 }
 
-display_impl!(bool);
-
-display_impl!(i8);
-display_impl!(i16);
-display_impl!(i32);
-display_impl!(i64);
-display_impl!(i128);
-display_impl!(isize);
-
-display_impl!(u8);
-display_impl!(u16);
-display_impl!(u32);
-display_impl!(u64);
-display_impl!(u128);
-display_impl!(usize);
-
-display_impl!(f32);
-display_impl!(f64);
-
-display_impl!(std::num::NonZeroI8);
-display_impl!(std::num::NonZeroI16);
-display_impl!(std::num::NonZeroI32);
-display_impl!(std::num::NonZeroI64);
-display_impl!(std::num::NonZeroI128);
-display_impl!(std::num::NonZeroIsize);
-
-display_impl!(std::num::NonZeroU8);
-display_impl!(std::num::NonZeroU16);
-display_impl!(std::num::NonZeroU32);
-display_impl!(std::num::NonZeroU64);
-display_impl!(std::num::NonZeroU128);
-display_impl!(std::num::NonZeroUsize);
+std_types! {
+    str: str_impl('"'),
+    char: str_impl('\''),
+    deref: deref_impl,
+    list: list_impl,
+    set: set_impl,
+    map: map_impl,
+    tuple: tuple_impl,
+    bool: display_impl(false),
+    number: display_impl,
+    non_zero: display_impl,
+    address: display_impl(false),
+}
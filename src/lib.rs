@@ -57,6 +57,50 @@
 //! );
 //! ```
 //!
+//! Skip a field based on its value at runtime:
+//!
+//! ```rust
+//! use deboog::Deboog;
+//!
+//! #[derive(Deboog)]
+//! struct Data {
+//!     #[deboog(skip_if = "Option::is_none")]
+//!     optional: Option<i32>,
+//!     #[deboog(skip_if = "Vec::is_empty")]
+//!     list: Vec<i32>,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", Data { optional: None, list: vec![123] }),
+//!     r#"Data { list: [123] }"#
+//! );
+//! ```
+//!
+//! ## Renaming
+//!
+//! Type, fields and enum variants can be renamed, either one by one or using a case convention:
+//!
+//! ```rust
+//! use deboog::Deboog;
+//!
+//! #[derive(Deboog)]
+//! #[deboog(rename = "User", rename_all = "camelCase")]
+//! struct UserRecord {
+//!     user_id: i32,
+//!     #[deboog(rename = "name")]
+//!     full_name: &'static str,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", UserRecord { user_id: 123, full_name: "John Doe" }),
+//!     r#"User { userId: 123, name: "John Doe" }"#
+//! );
+//! ```
+//!
+//! Supported conventions are `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`,
+//! `SCREAMING_SNAKE_CASE`, `kebab-case` and `SCREAMING-KEBAB-CASE`. In enums, `rename_all` on the
+//! type applies to variant names, and `rename_all` on a variant applies to its fields.
+//!
 //! ## Masking
 //!
 //! Mask a field:
@@ -95,6 +139,129 @@
 //! );
 //! ```
 //!
+//! `pan` masks any value by its length. Card numbers can be validated first, so values that are
+//! not card numbers are masked completely and never partially leak:
+//!
+//! ```rust
+//! use deboog::Deboog;
+//!
+//! #[derive(Deboog)]
+//! struct Data {
+//!     #[deboog(mask = "card")]
+//!     card: &'static str,
+//!     #[deboog(mask(card(brand = true)))]
+//!     card_brand: &'static str,
+//!     #[deboog(mask = "card")]
+//!     not_card: &'static str,
+//! }
+//!
+//! assert_eq!(
+//!     format!(
+//!         "{:?}",
+//!         Data {
+//!             card: "4111 1111 1111 1111",
+//!             card_brand: "5555-5555-5555-4444",
+//!             not_card: "4111 1111 1111 1112",
+//!         }
+//!     ),
+//!     r#"Data { card: "4111 11** **** 1111", card_brand: "MASTERCARD ****4444", not_card: "*******************" }"#
+//! );
+//! ```
+//!
+//! Card numbers must have 13 to 19 digits, optionally separated by spaces or dashes, and pass the
+//! Luhn check. With `brand = true`, only the card brand and last four digits are left.
+//!
+//! Leave a custom number of characters unmasked at the beginning and at the end, optionally using
+//! a different mask character:
+//!
+//! ```rust
+//! use deboog::Deboog;
+//!
+//! #[derive(Deboog)]
+//! struct Data {
+//!     #[deboog(mask(keep_start = 2, keep_end = 3, char = '#'))]
+//!     account: &'static str,
+//!     #[deboog(mask(keep_end = 4))]
+//!     national_id: &'static str,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", Data { account: "40817810099910004312", national_id: "4510123456" }),
+//!     r#"Data { account: "40###############312", national_id: "******3456" }"#
+//! );
+//! ```
+//!
+//! Mask an e-mail address, optionally masking the domain too:
+//!
+//! ```rust
+//! use deboog::Deboog;
+//!
+//! #[derive(Deboog)]
+//! struct Data {
+//!     #[deboog(mask = "email")]
+//!     email: &'static str,
+//!     #[deboog(mask(email(keep_domain = false)))]
+//!     private_email: &'static str,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", Data { email: "john@example.com", private_email: "john@example.com" }),
+//!     r#"Data { email: "j***@example.com", private_email: "j***@*******.com" }"#
+//! );
+//! ```
+//!
+//! Values that are not valid e-mail addresses are masked completely. Another strategy can be chosen
+//! for them with `fallback` parameter, e.g. `#[deboog(mask(email(fallback = "preserve_format")))]`.
+//! Any strategy except `hidden` can be used as a fallback.
+//!
+//! Mask host part of an IP or socket address, or the whole address leaving only the port:
+//!
+//! ```rust
+//! use std::net::{IpAddr, SocketAddr};
+//! use deboog::Deboog;
+//!
+//! #[derive(Deboog)]
+//! struct Data {
+//!     #[deboog(mask = "ip_subnet")]
+//!     client: IpAddr,
+//!     #[deboog(mask = "ip_port")]
+//!     upstream: SocketAddr,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", Data {
+//!         client: "192.168.1.23".parse().unwrap(),
+//!         upstream: "10.0.0.1:8080".parse().unwrap(),
+//!     }),
+//!     r#"Data { client: 192.168.1.x, upstream: x.x.x.x:8080 }"#
+//! );
+//! ```
+//!
+//! IPv6 addresses keep the first three segments with `ip_subnet`. Values that are not IP or socket
+//! addresses are masked completely.
+//!
+//! With `hash` feature enabled, a value can be replaced with a short keyed fingerprint, so that
+//! the same secret can be correlated across log lines without revealing it:
+//!
+#![cfg_attr(feature = "hash", doc = "```rust")]
+#![cfg_attr(not(feature = "hash"), doc = "```rust,ignore")]
+//! use deboog::{masking, Deboog};
+//!
+//! #[derive(Deboog)]
+//! struct Data {
+//!     #[deboog(mask = "hash")]
+//!     token: &'static str,
+//! }
+//!
+//! masking::set_hash_key("secret key from configuration");
+//!
+//! // Prints something like `Data { token: "sha256:3fa9c1d2e4b5…" }`
+//! println!("{:?}", Data { token: "0123456789" });
+//! ```
+//!
+//! Fingerprints are truncated HMAC-SHA256 values. Until the key is set with `masking::set_hash_key`,
+//! such fields are masked completely. Using `mask = "hash"` without the feature is a compile error.
+//!
 //! In case you need to hide real field length:
 //!
 //! ```rust
@@ -113,6 +280,359 @@
 //! );
 //! ```
 //!
+//! Or reveal only the length of a value, either exactly or as a rounded range:
+//!
+//! ```rust
+//! use deboog::Deboog;
+//!
+//! #[derive(Deboog)]
+//! struct Data {
+//!     #[deboog(mask = "length")]
+//!     token: &'static str,
+//!     #[deboog(mask = "length_bucket")]
+//!     password: &'static str,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", Data { token: "0123456789012345", password: "qwerty123456" }),
+//!     r#"Data { token: "<redacted 16 chars>", password: "<redacted ~10-20 chars>" }"#
+//! );
+//! ```
+//!
+//! Range bounds follow 1, 2, 5, 10, 20, 50, … sequence. Empty values are always reported exactly.
+//!
+//! Phone numbers keep their separators, country code and last digits:
+//!
+//! ```rust
+//! use deboog::Deboog;
+//!
+//! #[derive(Deboog)]
+//! struct Data {
+//!     #[deboog(mask = "phone")]
+//!     mobile: &'static str,
+//!     #[deboog(mask = "phone")]
+//!     office: &'static str,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", Data { mobile: "+7 (912) 345-67-67", office: "+1 415-555-0132" }),
+//!     r#"Data { mobile: "+7 (***) ***-**-67", office: "+1 ***-***-0132" }"#
+//! );
+//! ```
+//!
+//! The number of unmasked trailing digits follows the last group of digits, from 2 to 4.
+//! Values with other characters or too few digits are masked completely.
+//!
+//! To keep the shape of identifiers, digits and letters can be replaced by their classes. Whitespace
+//! and punctuation are kept, while other characters such as symbols and emoji are replaced with `*`:
+//!
+//! ```rust
+//! use deboog::Deboog;
+//!
+//! #[derive(Deboog)]
+//! struct Data {
+//!     #[deboog(mask = "preserve_format")]
+//!     passport: &'static str,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", Data { passport: "AB12-3456 7890" }),
+//!     r#"Data { passport: "XX##-#### ####" }"#
+//! );
+//! ```
+//!
+//! Mask all fields by default, only leaving explicitly unmasked fields readable:
+//!
+//! ```rust
+//! use deboog::Deboog;
+//!
+//! #[derive(Deboog)]
+//! #[deboog(mask = "hidden")]
+//! struct Data {
+//!     #[deboog(unmask)]
+//!     id: i32,
+//!     name: &'static str,
+//!     #[deboog(mask = "pan")]
+//!     card: &'static str,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", Data { id: 123, name: "John", card: "1111222233334444" }),
+//!     r#"Data { id: 123, name: ***, card: "111122******4444" }"#
+//! );
+//! ```
+//!
+//! Replacement text can be customized per field, or for the whole type:
+//!
+//! ```rust
+//! use deboog::Deboog;
+//!
+//! #[derive(Deboog)]
+//! #[deboog(placeholder = "[REDACTED]")]
+//! struct Data {
+//!     #[deboog(mask = "hidden")]
+//!     password: &'static str,
+//!     #[deboog(mask = "hidden", placeholder = "<redacted>")]
+//!     token: &'static str,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", Data { password: "qwerty", token: "0123456789" }),
+//!     r#"Data { password: [REDACTED], token: <redacted> }"#
+//! );
+//! ```
+//!
+//! Masked numbers, booleans and addresses honor width, fill, alignment, precision, sign and zero
+//! padding options of the formatter: `{:+08.2?}` pads and signs the value before masking it, so
+//! that the masked output has the same length as the plain one. Hexadecimal debug flags (`{:x?}`,
+//! `{:X?}`) are not exposed by `std::fmt::Formatter`, so masked numbers are always rendered in
+//! decimal form.
+//!
+//! ## Serialization
+//!
+//! With `serde` feature enabled, `DeboogSerialize` derive macro implements `serde::Serialize`
+//! using the same `#[deboog(...)]` attributes, so masking rules are kept in one place:
+//!
+#![cfg_attr(feature = "serde", doc = "```rust")]
+#![cfg_attr(not(feature = "serde"), doc = "```rust,ignore")]
+//! use deboog::{Deboog, DeboogSerialize};
+//!
+//! #[derive(Deboog, DeboogSerialize)]
+//! struct Data {
+//!     id: i32,
+//!     #[deboog(mask = "pan")]
+//!     card: String,
+//!     #[deboog(skip)]
+//!     password: String,
+//! }
+//!
+//! let data = Data { id: 123, card: "1111222233334444".into(), password: "qwerty".into() };
+//! assert_eq!(
+//!     serde_json::to_string(&data).unwrap(),
+//!     r#"{"id":123,"card":"111122******4444"}"#
+//! );
+//! ```
+//!
+//! Masked values are serialized as strings, while collections, options and tuples keep their
+//! structure. Type parameters get `Serialize` bounds instead of `Debug` ones, and a custom
+//! `#[deboog(bound = "...")]` applies to both derives. Fields with a custom formatting function
+//! are serialized as strings produced by that function. Support for masking custom field types
+//! is implemented using `deboog::serde::SerializeMasked` trait.
+//!
+//! ## Structured logging
+//!
+//! Types deriving `Deboog` can be recorded by `tracing` without any features, as a single masked
+//! string: `tracing::info!(data = ?data, "payment accepted")`.
+//!
+//! To record each field as a separate structured value, `DeboogValuable` derive macro implements
+//! [`valuable::Valuable`](https://docs.rs/valuable) using the same `#[deboog(...)]` attributes.
+//! It is enabled by `valuable` feature, or by `tracing` feature, which is only an alias of
+//! `valuable` and doesn't integrate with `tracing` by itself. Recording these values with
+//! `tracing` requires all of:
+//!
+//! - `#[derive(DeboogValuable)]` on the type, in addition to `Deboog`;
+//! - `valuable` feature of `tracing` crate;
+//! - building with `RUSTFLAGS="--cfg tracing_unstable"`, since `valuable` support in `tracing` is
+//!   unstable.
+//!
+#![cfg_attr(feature = "valuable", doc = "```rust")]
+#![cfg_attr(not(feature = "valuable"), doc = "```rust,ignore")]
+//! use deboog::{Deboog, DeboogValuable};
+//! use valuable::Valuable;
+//!
+//! #[derive(Deboog, DeboogValuable)]
+//! struct Data {
+//!     id: i32,
+//!     #[deboog(mask = "pan")]
+//!     card: String,
+//!     #[deboog(skip)]
+//!     password: String,
+//! }
+//!
+//! let data = Data { id: 123, card: "1111222233334444".into(), password: "qwerty".into() };
+//! // Recorded with `tracing::info!(data = data.as_value(), "payment accepted")`
+//! assert_eq!(
+//!     format!("{:?}", data.as_value()),
+//!     r#"Data { id: 123, card: "111122******4444" }"#
+//! );
+//! ```
+//!
+//! The same values can be used by any other `valuable` consumer. Masked values are recorded as
+//! strings, while collections, options and tuples keep their structure. Type parameters get
+//! `Valuable` bounds, and support for masking custom field types is implemented using
+//! `deboog::valuable::ValuableMasked` trait.
+//!
+//! ## Revealing masked values
+//!
+//! Masking can be disabled at runtime for local debugging or test snapshots, either for the whole
+//! process, or for the current thread until a guard is dropped:
+//!
+//! ```rust
+//! use deboog::{policy::{self, Policy}, Deboog};
+//!
+//! #[derive(Deboog)]
+//! struct Data {
+//!     #[deboog(mask = "all")]
+//!     token: &'static str,
+//! }
+//!
+//! let data = Data { token: "secret" };
+//! {
+//!     let _guard = policy::scoped(Policy::Reveal);
+//!     assert_eq!(format!("{:?}", data), r#"Data { token: "secret" }"#);
+//! }
+//! assert_eq!(format!("{:?}", data), r#"Data { token: "******" }"#);
+//! ```
+//!
+//! Process-wide policy is set with `policy::set`, or initially with `DEBOOG_REVEAL=1` environment
+//! variable. Revealing only works in builds with debug assertions enabled, unless
+//! `reveal-in-release` feature is enabled.
+//!
+//! ## Generics
+//!
+//! Generic types are supported. Type parameters get `Debug` bounds, and also `DeboogField` bounds
+//! if they are used in masked fields. Bounds are only inferred for type parameters themselves, so
+//! fields of associated types, like `B::Key`, are left without bounds and fail to compile.
+//! Custom bounds can be provided instead:
+//!
+//! ```rust
+//! use deboog::Deboog;
+//!
+//! trait Backend {
+//!     type Key;
+//! }
+//!
+//! struct Local;
+//!
+//! impl Backend for Local {
+//!     type Key = u32;
+//! }
+//!
+//! #[derive(Deboog)]
+//! #[deboog(bound = "B::Key: std::fmt::Debug")]
+//! struct Handle<B: Backend> {
+//!     key: B::Key,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", Handle::<Local> { key: 123 }),
+//!     r#"Handle { key: 123 }"#
+//! );
+//! ```
+//!
+//! Standard collections, slices, arrays and tuples are masked element by element. Smart pointers and
+//! wrappers like `Box`, `Arc`, `Cow` or `Mutex` mask the value they contain. Keys and values
+//! of maps can also be masked separately, and the unmasked side only needs to implement `Debug`:
+//!
+//! ```rust
+//! use std::collections::BTreeMap;
+//!
+//! use deboog::Deboog;
+//!
+//! #[derive(Deboog)]
+//! struct Data {
+//!     #[deboog(mask_values = "all")]
+//!     headers: BTreeMap<&'static str, &'static str>,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", Data { headers: BTreeMap::from([("Authorization", "Bearer 123")]) }),
+//!     r#"Data { headers: {"Authorization": "**********"} }"#
+//! );
+//! ```
+//!
+//! Byte buffers can be rendered as hex or base64 strings, which are then masked as a whole, or
+//! reduced to their length:
+//!
+//! ```rust
+//! use deboog::Deboog;
+//!
+//! #[derive(Deboog)]
+//! struct Data {
+//!     #[deboog(bytes = "hex", mask(keep_start = 2))]
+//!     key_id: Vec<u8>,
+//!     #[deboog(bytes = "length")]
+//!     key: Vec<u8>,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", Data { key_id: vec![0xde, 0xad, 0xbe, 0xef], key: vec![0; 32] }),
+//!     r#"Data { key_id: de******, key: [u8; 32 bytes] }"#
+//! );
+//! ```
+//!
+//! ## Custom formatting
+//!
+//! Output for a single field can be overridden with a function, which also works for foreign
+//! types that do not implement `DeboogField`:
+//!
+//! ```rust
+//! use std::{fmt, time::Duration};
+//!
+//! use deboog::Deboog;
+//!
+//! fn fmt_secs(value: &Duration, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!     write!(f, "{}s", value.as_secs())
+//! }
+//!
+//! #[derive(Deboog)]
+//! struct Data {
+//!     #[deboog(with = "fmt_secs")]
+//!     timeout: Duration,
+//! }
+//!
+//! assert_eq!(
+//!     format!("{:?}", Data { timeout: Duration::from_secs(30) }),
+//!     r#"Data { timeout: 30s }"#
+//! );
+//! ```
+//!
+//! ## Display
+//!
+//! `DeboogDisplay` derive macro implements `Display` from a template, applying masking attributes
+//! of the referenced fields. Fields are referenced by name, or by position for tuple structs, and
+//! can use the usual format specs:
+//!
+//! ```rust
+//! use deboog::DeboogDisplay;
+//!
+//! #[derive(DeboogDisplay)]
+//! #[deboog(display = "{name} <{email}>, card {card}")]
+//! struct User {
+//!     name: String,
+//!     #[deboog(mask = "email")]
+//!     email: String,
+//!     #[deboog(mask = "pan_suffix")]
+//!     card: String,
+//! }
+//!
+//! #[derive(DeboogDisplay)]
+//! #[deboog(display = "payment {id} accepted")]
+//! enum Event {
+//!     #[deboog(display = "card {card} declined")]
+//!     Declined {
+//!         #[deboog(mask = "pan_suffix")]
+//!         card: String,
+//!     },
+//!     Accepted { id: u32 },
+//! }
+//!
+//! let user = User {
+//!     name: "John".into(),
+//!     email: "john@example.com".into(),
+//!     card: "1111222233334444".into(),
+//! };
+//! assert_eq!(user.to_string(), "John <j***@example.com>, card *4444");
+//! assert_eq!(Event::Accepted { id: 7 }.to_string(), "payment 7 accepted");
+//! ```
+//!
+//! Enum variants use their own template, or the one set on the enum. Masked fields are formatted
+//! with `Display` and then masked, so they only need to implement `Display`. Hidden fields don't
+//! need `Display`: they are written as placeholders, or as in `Deboog` output when masked values
+//! are revealed. Fields with `bytes` attribute are encoded the same way as in `Deboog` output.
+//! Debug formatting (`{field:?}`) is not supported in templates. Type parameters get `Display`
+//! bounds only if referenced fields use them.
+//!
 //! ## Type support
 //!
 //! Support for masking for custom field types can be implemented using [`field::DeboogField`] trait:
@@ -141,16 +661,63 @@
 //!     r#"Data { unmasked: What, masked: WHAT? }"#
 //! );
 //! ```
+//!
+//! ## Cargo features
+//!
+//! - `graphemes`: count and mask extended grapheme clusters instead of chars.
+//! - `hash`: enable keyed fingerprint masking strategy, `#[deboog(mask = "hash")]`.
+//! - `reveal-in-release`: allow revealing masked values in builds without debug assertions.
+//! - `serde`: enable `DeboogSerialize` derive macro for masked serialization.
+//! - `tracing`: alias of `valuable`, see [Structured logging](#structured-logging) for
+//!   requirements of recording masked values with `tracing`.
+//! - `valuable`: enable `DeboogValuable` derive macro for other `valuable` consumers.
 
 #![warn(missing_docs)]
 
+#[macro_use]
+mod types;
+
 /// Field conversion trait impls
 pub mod field;
 /// String masking utilities
 pub mod masking;
+/// Runtime masking policy
+pub mod policy;
+/// Masked serialization support
+#[cfg(feature = "serde")]
+pub mod serde;
+/// Masked structured values for `tracing` and other `valuable` consumers
+#[cfg(feature = "valuable")]
+pub mod valuable;
 
 /// #[derive(Debug)] with extra features
 pub use deboog_derive::Deboog;
+/// #[derive(Display)] from a template, with the same masking rules as [`Deboog`]
+pub use deboog_derive::DeboogDisplay;
+/// #[derive(Serialize)] with the same masking rules as [`Deboog`]
+#[cfg(feature = "serde")]
+pub use deboog_derive::DeboogSerialize;
+/// #[derive(Valuable)] with the same masking rules as [`Deboog`]
+#[cfg(feature = "valuable")]
+pub use deboog_derive::DeboogValuable;
 
 pub use field::DeboogField;
 pub use masking::MaskType;
+
+/// Reports `#[deboog(mask = "hash")]` used without `hash` feature
+#[doc(hidden)]
+#[cfg(feature = "hash")]
+#[macro_export]
+macro_rules! __require_hash_feature {
+    () => {};
+}
+
+/// Reports `#[deboog(mask = "hash")]` used without `hash` feature
+#[doc(hidden)]
+#[cfg(not(feature = "hash"))]
+#[macro_export]
+macro_rules! __require_hash_feature {
+    () => {
+        compile_error!("`mask = \"hash\"` requires `hash` feature of `deboog` crate");
+    };
+}
@@ -1,9 +1,10 @@
-use std::iter::repeat;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 
 /// Default replacement value for `#[deboog(mask = "hidden")]`
 pub const HIDE_STR: &str = "***";
 
 /// Mask type
+#[non_exhaustive]
 #[derive(Clone, Copy)]
 pub enum MaskType {
     /// Replaces all characters with `*`
@@ -14,47 +15,605 @@ pub enum MaskType {
     Pan,
     /// Replaces all but last four characters with a single `*` symbol
     PanSuffix,
+    /// Masks card number, leaving BIN and last four digits, e.g. `4111 11** **** 1111`
+    ///
+    /// Values are only treated as card numbers if they have 13 to 19 digits, optionally
+    /// separated by spaces or dashes, and pass the Luhn check. Other values are masked using
+    /// [`MaskType::All`].
+    Card {
+        /// Show card brand and last four digits instead, e.g. `VISA ****1111`
+        brand: bool,
+    },
+    /// Masks e-mail address, leaving first character of the local part
+    ///
+    /// Single-character local parts are masked completely. Domain is either left as is,
+    /// or masked except for the top-level domain.
+    /// Values that are not valid e-mail addresses are masked using `fallback` mask type.
+    Email {
+        /// Leave domain part unmasked
+        keep_domain: bool,
+        /// Mask type for values that are not e-mail addresses, [`MaskType::All`] by default
+        fallback: &'static MaskType,
+    },
+    /// Replaces all characters with `mask_char`, except for configured number of leading
+    /// and trailing characters
+    ///
+    /// Values too short to have at least one masked character are masked completely.
+    Partial {
+        /// Number of leading characters to leave unmasked
+        keep_start: usize,
+        /// Number of trailing characters to leave unmasked
+        keep_end: usize,
+        /// Character to use for masking
+        mask_char: char,
+    },
+    /// Masks host part of an IP address, leaving /24 prefix for IPv4 and /48 prefix for IPv6
+    ///
+    /// Port of a socket address is left unmasked. Values that are not IP or socket addresses
+    /// are masked using [`MaskType::All`].
+    IpSubnet,
+    /// Masks IP address completely, leaving port of a socket address unmasked
+    ///
+    /// Values that are not IP or socket addresses are masked using [`MaskType::All`].
+    IpPort,
+    /// Replaces value with a short keyed fingerprint, e.g. `sha256:3fa9c1d2e4b5…`
+    ///
+    /// Fingerprint is a truncated HMAC-SHA256 of the value, keyed with `set_hash_key`.
+    /// Values are masked using [`MaskType::All`] if the key has not been set, or if `hash`
+    /// feature is disabled.
+    Hash,
+    /// Replaces value with its length, e.g. `<redacted 16 chars>`
+    Length,
+    /// Replaces value with a rounded range of its length, e.g. `<redacted ~10-20 chars>`
+    ///
+    /// Range bounds follow 1, 2, 5, 10, 20, 50, … sequence. Empty values are reported exactly.
+    LengthBucket,
+    /// Masks digits of a phone number, leaving leading `+` with country code and last 2-4 digits
+    ///
+    /// Separators such as spaces, dashes and parentheses are kept, e.g. `+7 (***) ***-**-67`.
+    /// Number of unmasked trailing digits follows the last group of digits.
+    /// Values that are not phone numbers or have too few digits are masked using [`MaskType::All`].
+    Phone,
+    /// Replaces digits with `#` and letters with `x` or `X`, keeping whitespace and punctuation
+    ///
+    /// Keeps layout of identifiers, e.g. `AB12-3456 7890` becomes `XX##-#### ####`.
+    /// Other characters, such as symbols and emoji, are replaced with `*`.
+    PreserveFormat,
+}
+
+/// Rendering of byte buffers
+#[derive(Clone, Copy)]
+pub enum BytesEncoding {
+    /// Lowercase hexadecimal string
+    Hex,
+    /// Standard base64 string with padding
+    Base64,
+    /// Only the length of the buffer, e.g. `[u8; 32 bytes]`
+    Length,
 }
 
 /// Produces masked string based on mask type
+///
+/// Returns the value as is if masking is disabled by [`crate::policy`].
 pub fn mask(value: &str, mask_type: MaskType) -> String {
+    if crate::policy::reveals() {
+        return value.to_owned();
+    }
     match mask_type {
         MaskType::All => mask_all(value),
         MaskType::Pan => mask_pan(value),
         MaskType::PanSuffix => mask_pan_suffix(value),
+        MaskType::Card { brand } => mask_card(value, brand),
+        MaskType::Email {
+            keep_domain,
+            fallback,
+        } => try_mask_email(value, keep_domain).unwrap_or_else(|| mask(value, *fallback)),
+        MaskType::Partial {
+            keep_start,
+            keep_end,
+            mask_char,
+        } => mask_partial(value, keep_start, keep_end, mask_char),
+        MaskType::IpSubnet => mask_ip_subnet(value),
+        MaskType::IpPort => mask_ip_port(value),
+        MaskType::Length => mask_length(value),
+        MaskType::LengthBucket => mask_length_bucket(value),
+        MaskType::Phone => mask_phone(value),
+        MaskType::PreserveFormat => mask_preserve_format(value),
+        MaskType::Hash => mask_hash(value),
     }
 }
 
+/// Splits string into units that are masked or revealed as a whole
+///
+/// Units are extended grapheme clusters if `graphemes` feature is enabled, and chars otherwise.
+#[cfg(feature = "graphemes")]
+fn units(value: &str) -> impl Iterator<Item = &str> + Clone {
+    use unicode_segmentation::UnicodeSegmentation;
+    value.graphemes(true)
+}
+
+/// Splits string into units that are masked or revealed as a whole
+///
+/// Units are extended grapheme clusters if `graphemes` feature is enabled, and chars otherwise.
+#[cfg(not(feature = "graphemes"))]
+fn units(value: &str) -> impl Iterator<Item = &str> + Clone {
+    value
+        .char_indices()
+        .map(move |(i, c)| &value[i..i + c.len_utf8()])
+}
+
 /// Replaces all characters with `*`
 pub fn mask_all(value: &str) -> String {
-    "*".repeat(value.len())
+    "*".repeat(units(value).count())
 }
 
 /// Same as [`mask_all`], but leaves unmasked characters according to PAN masking convention
 ///
 /// Leaves 6 characters in the beginning and 4 characters at the end of the string.
 pub fn mask_pan(value: &str) -> String {
-    let sz = value.len();
-    let limit = if sz > 4 { sz - 4 } else { 0 };
-    value
-        .chars()
+    let sz = units(value).count();
+    let limit = sz.saturating_sub(4);
+    units(value)
         .enumerate()
-        .map(|(i, c)| if i < 6 || i >= limit { c } else { '*' })
+        .map(|(i, c)| if i < 6 || i >= limit { c } else { "*" })
         .collect()
 }
 
 /// Replaces all but last four characters with a single `*` symbol
 pub fn mask_pan_suffix(value: &str) -> String {
-    let sz = value.len();
-    let limit = if sz > 4 { sz - 4 } else { 0 };
-    repeat('*')
-        .take(if sz > 4 { 1 } else { 0 })
-        .chain(value[limit..].chars())
+    let sz = units(value).count();
+    let limit = sz.saturating_sub(4);
+    (sz > 4)
+        .then_some("*")
+        .into_iter()
+        .chain(units(value).skip(limit))
         .collect()
 }
 
+/// Number of leading card number digits identifying the issuer, which may be left unmasked
+const CARD_BIN_LEN: usize = 6;
+
+/// Number of trailing card number digits which may be left unmasked
+const CARD_LAST_LEN: usize = 4;
+
+/// Masks card number, leaving BIN and last four digits, e.g. `4111 11** **** 1111`
+///
+/// If `brand` is true, only leaves card brand and last four digits instead, e.g. `VISA ****1111`.
+/// Falls back to [`mask_all`] unless the value has 13 to 19 digits, optionally separated by
+/// spaces or dashes, and passes the Luhn check.
+pub fn mask_card(value: &str, brand: bool) -> String {
+    if !value
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == ' ' || c == '-')
+    {
+        return mask_all(value);
+    }
+    let digits: Vec<u8> = value
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .map(|c| c - b'0')
+        .collect();
+    if !(13..=19).contains(&digits.len()) || !luhn_valid(&digits) {
+        return mask_all(value);
+    }
+    let last: String = value
+        .chars()
+        .filter(char::is_ascii_digit)
+        .skip(digits.len() - CARD_LAST_LEN)
+        .collect();
+    if brand {
+        return match card_brand(&digits) {
+            Some(brand) => format!("{} ****{}", brand, last),
+            None => format!("****{}", last),
+        };
+    }
+    let mut index = 0;
+    value
+        .chars()
+        .map(|c| {
+            if !c.is_ascii_digit() {
+                return c;
+            }
+            let keep = index < CARD_BIN_LEN || index >= digits.len() - CARD_LAST_LEN;
+            index += 1;
+            if keep {
+                c
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+/// Checks Luhn checksum of card number digits
+fn luhn_valid(digits: &[u8]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &digit)| {
+            let digit = u32::from(digit);
+            match (i % 2, digit * 2) {
+                (0, _) => digit,
+                (_, doubled) if doubled > 9 => doubled - 9,
+                (_, doubled) => doubled,
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+/// Detects card brand by the leading digits of card number
+fn card_brand(digits: &[u8]) -> Option<&'static str> {
+    let prefix = |len: usize| {
+        digits[..len]
+            .iter()
+            .fold(0u32, |acc, &digit| acc * 10 + u32::from(digit))
+    };
+    let brand = match (prefix(2), prefix(3), prefix(4)) {
+        (_, _, 2200..=2204) => "MIR",
+        (51..=55, _, _) | (_, _, 2221..=2720) => "MASTERCARD",
+        (34 | 37, _, _) => "AMEX",
+        (_, _, 6011) | (_, 644..=649, _) | (65, _, _) => "DISCOVER",
+        (_, _, 3528..=3589) => "JCB",
+        (36 | 38 | 39, _, _) | (_, 300..=305, _) => "DINERS",
+        (62, _, _) => "UNIONPAY",
+        (50 | 56..=58 | 63 | 67, _, _) => "MAESTRO",
+        (40..=49, _, _) => "VISA",
+        _ => return None,
+    };
+    Some(brand)
+}
+
+/// Replaces all characters with `mask_char`, except for `keep_start` leading
+/// and `keep_end` trailing characters
+///
+/// Values too short to have at least one masked character are masked completely.
+pub fn mask_partial(value: &str, keep_start: usize, keep_end: usize, mask_char: char) -> String {
+    let sz = units(value).count();
+    let reveal = sz > keep_start.saturating_add(keep_end);
+    let limit = sz.saturating_sub(keep_end);
+    let mut buf = [0; 4];
+    let mask_str: &str = mask_char.encode_utf8(&mut buf);
+    units(value)
+        .enumerate()
+        .map(|(i, c)| {
+            if reveal && (i < keep_start || i >= limit) {
+                c
+            } else {
+                mask_str
+            }
+        })
+        .collect()
+}
+
+/// Masks e-mail address, leaving first character of the local part
+///
+/// Single-character local parts are masked completely. If `keep_domain` is false, also masks
+/// the domain, except for the top-level domain.
+/// Falls back to [`mask_all`] if the value is not a valid e-mail address.
+pub fn mask_email(value: &str, keep_domain: bool) -> String {
+    try_mask_email(value, keep_domain).unwrap_or_else(|| mask_all(value))
+}
+
+/// Masks e-mail address, or returns `None` if the value is not a valid e-mail address
+fn try_mask_email(value: &str, keep_domain: bool) -> Option<String> {
+    let (local, domain) = match value.rsplit_once('@') {
+        Some((local, domain)) if is_email(local, domain) => (local, domain),
+        _ => return None,
+    };
+    // Single-unit local part would be left unmasked completely
+    let keep = usize::from(units(local).nth(1).is_some());
+    let mut masked: String = units(local)
+        .enumerate()
+        .map(|(i, c)| if i < keep { c } else { "*" })
+        .collect();
+    masked.push('@');
+    if keep_domain {
+        masked.push_str(domain);
+    } else {
+        // is_email() guarantees there is at least one dot
+        let (name, tld) = domain.rsplit_once('.').unwrap_or((domain, ""));
+        masked.push_str(&mask_all(name));
+        masked.push('.');
+        masked.push_str(tld);
+    }
+    Some(masked)
+}
+
+/// Loosely checks local and domain parts of an e-mail address
+fn is_email(local: &str, domain: &str) -> bool {
+    !local.is_empty()
+        && !local.contains('@')
+        && !local.chars().any(char::is_whitespace)
+        && domain.contains('.')
+        && domain.split('.').all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+/// Masks host part of an IP address, leaving /24 prefix for IPv4 and /48 prefix for IPv6
+///
+/// Port of a socket address is left unmasked.
+/// Falls back to [`mask_all`] if the value is not an IP or socket address.
+pub fn mask_ip_subnet(value: &str) -> String {
+    mask_ip_or_socket(value, true)
+}
+
+/// Masks IP address completely, leaving port of a socket address unmasked
+///
+/// Falls back to [`mask_all`] if the value is not an IP or socket address.
+pub fn mask_ip_port(value: &str) -> String {
+    mask_ip_or_socket(value, false)
+}
+
+fn mask_ip_or_socket(value: &str, keep_subnet: bool) -> String {
+    if let Ok(addr) = value.parse::<IpAddr>() {
+        return mask_ip(addr, keep_subnet);
+    }
+    match value.parse::<SocketAddr>() {
+        Ok(SocketAddr::V4(addr)) => {
+            format!("{}:{}", mask_ipv4(addr.ip(), keep_subnet), addr.port())
+        }
+        Ok(SocketAddr::V6(addr)) => {
+            format!("[{}]:{}", mask_ipv6(addr.ip(), keep_subnet), addr.port())
+        }
+        Err(_) => mask_all(value),
+    }
+}
+
+fn mask_ip(addr: IpAddr, keep_subnet: bool) -> String {
+    match addr {
+        IpAddr::V4(addr) => mask_ipv4(&addr, keep_subnet),
+        IpAddr::V6(addr) => mask_ipv6(&addr, keep_subnet),
+    }
+}
+
+fn mask_ipv4(addr: &Ipv4Addr, keep_subnet: bool) -> String {
+    let octets = addr.octets();
+    let keep = if keep_subnet { 3 } else { 0 };
+    let parts: Vec<String> = (0..4)
+        .map(|i| {
+            if i < keep {
+                octets[i].to_string()
+            } else {
+                "x".to_owned()
+            }
+        })
+        .collect();
+    parts.join(".")
+}
+
+fn mask_ipv6(addr: &Ipv6Addr, keep_subnet: bool) -> String {
+    let segments = addr.segments();
+    let keep = if keep_subnet { 3 } else { 0 };
+    let parts: Vec<String> = (0..8)
+        .map(|i| {
+            if i < keep {
+                format!("{:x}", segments[i])
+            } else {
+                "x".to_owned()
+            }
+        })
+        .collect();
+    parts.join(":")
+}
+
+/// Replaces value with its length, e.g. `<redacted 16 chars>`
+pub fn mask_length(value: &str) -> String {
+    let sz = units(value).count();
+    format!(
+        "<redacted {} {}>",
+        sz,
+        if sz == 1 { "char" } else { "chars" }
+    )
+}
+
+/// Replaces value with a rounded range of its length, e.g. `<redacted ~10-20 chars>`
+///
+/// Range bounds follow 1, 2, 5, 10, 20, 50, … sequence. Empty values are reported exactly.
+pub fn mask_length_bucket(value: &str) -> String {
+    let sz = units(value).count();
+    if sz == 0 {
+        return mask_length(value);
+    }
+    let mut base = 1usize;
+    loop {
+        for (low, high) in [(1, 2), (2, 5), (5, 10)] {
+            let low = base * low;
+            match base.checked_mul(high) {
+                Some(high) if sz < high => return format!("<redacted ~{}-{} chars>", low, high),
+                Some(_) => {}
+                None => return format!("<redacted ~{}+ chars>", low),
+            }
+        }
+        base *= 10;
+    }
+}
+
+/// Replaces digits with `#` and letters with `x` or `X`, keeping whitespace and punctuation
+///
+/// Keeps layout of identifiers, e.g. `AB12-3456 7890` becomes `XX##-#### ####`. Letters are
+/// replaced with `X` if they are uppercase, and with `x` otherwise. Only whitespace and ASCII
+/// punctuation are kept as is, any other characters are replaced with `*`.
+pub fn mask_preserve_format(value: &str) -> String {
+    units(value)
+        .map(|unit| match unit.chars().next() {
+            Some(c) if c.is_numeric() => "#",
+            Some(c) if c.is_uppercase() => "X",
+            Some(c) if c.is_alphabetic() => "x",
+            Some(c) if c.is_whitespace() || c.is_ascii_punctuation() => unit,
+            _ => "*",
+        })
+        .collect()
+}
+
+/// Characters allowed between digits of a phone number
+const PHONE_SEPARATORS: &[char] = &[' ', '-', '.', '/', '(', ')'];
+
+/// Minimum number of digits of a phone number that must be masked
+const PHONE_MIN_MASKED: usize = 4;
+
+/// Masks digits of a phone number, leaving leading `+` with country code and last 2-4 digits
+///
+/// Separators are kept, e.g. `+7 (912) 345-67-67` becomes `+7 (***) ***-**-67`. Number of
+/// unmasked trailing digits follows the last group of digits.
+/// Falls back to [`mask_all`] if the value is not a phone number or has too few digits.
+pub fn mask_phone(value: &str) -> String {
+    let (plus, number) = match value.strip_prefix('+') {
+        Some(number) => ("+", number),
+        None => ("", value),
+    };
+    if !number
+        .chars()
+        .all(|c| c.is_ascii_digit() || PHONE_SEPARATORS.contains(&c))
+    {
+        return mask_all(value);
+    }
+    let digits: Vec<u8> = number.bytes().filter(u8::is_ascii_digit).collect();
+    let keep_start = if plus.is_empty() {
+        0
+    } else {
+        country_code_len(&digits)
+    };
+    let last_group = number.rsplit(PHONE_SEPARATORS).next().map_or(0, str::len);
+    let keep_end = last_group.clamp(2, 4);
+    if digits.len() < keep_start + keep_end + PHONE_MIN_MASKED {
+        return mask_all(value);
+    }
+    let mut index = 0;
+    let masked: String = number
+        .chars()
+        .map(|c| {
+            if !c.is_ascii_digit() {
+                return c;
+            }
+            let keep = index < keep_start || index >= digits.len() - keep_end;
+            index += 1;
+            if keep {
+                c
+            } else {
+                '*'
+            }
+        })
+        .collect();
+    format!("{}{}", plus, masked)
+}
+
+/// Length of the country calling code at the start of international number digits
+///
+/// Codes are prefix-free, so the length is determined by the first two digits.
+fn country_code_len(digits: &[u8]) -> usize {
+    match digits {
+        [b'1' | b'7', ..] => 1,
+        [b'2', b'0' | b'7', ..]
+        | [b'3', b'0'..=b'4' | b'6' | b'9', ..]
+        | [b'4', b'0' | b'1' | b'3'..=b'9', ..]
+        | [b'5', b'1'..=b'8', ..]
+        | [b'6', b'0'..=b'6', ..]
+        | [b'8', b'1' | b'2' | b'4' | b'6', ..]
+        | [b'9', b'0'..=b'5' | b'8', ..] => 2,
+        _ => 3,
+    }
+}
+
+/// Secret key for [`MaskType::Hash`] fingerprints
+#[cfg(feature = "hash")]
+static HASH_KEY: std::sync::RwLock<Option<Vec<u8>>> = std::sync::RwLock::new(None);
+
+/// Number of leading bytes of HMAC output shown in a fingerprint
+#[cfg(feature = "hash")]
+const HASH_FINGERPRINT_LEN: usize = 6;
+
+/// Sets secret key used to compute [`MaskType::Hash`] fingerprints
+///
+/// The key is shared by the whole process. Use the same key across instances of a service
+/// to be able to correlate values in their logs.
+#[cfg(feature = "hash")]
+pub fn set_hash_key(key: impl Into<Vec<u8>>) {
+    *HASH_KEY
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(key.into());
+}
+
+/// Removes secret key set by [`set_hash_key`]
+///
+/// Values masked using [`MaskType::Hash`] are masked using [`MaskType::All`] afterwards.
+#[cfg(feature = "hash")]
+pub fn clear_hash_key() {
+    *HASH_KEY
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = None;
+}
+
+/// Replaces value with a short keyed fingerprint, e.g. `sha256:3fa9c1d2e4b5…`
+///
+/// Fingerprint is a truncated HMAC-SHA256 of the value, keyed with `set_hash_key`.
+/// Falls back to [`mask_all`] if the key has not been set, or if `hash` feature is disabled.
+pub fn mask_hash(value: &str) -> String {
+    #[cfg(feature = "hash")]
+    if let Some(fingerprint) = hash_fingerprint(value) {
+        return fingerprint;
+    }
+    mask_all(value)
+}
+
+/// Computes fingerprint of the value, if the key has been set
+#[cfg(feature = "hash")]
+fn hash_fingerprint(value: &str) -> Option<String> {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    let guard = HASH_KEY
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let key = guard.as_deref()?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(value.as_bytes());
+    let digest = mac.finalize().into_bytes();
+    Some(format!(
+        "sha256:{}…",
+        encode_hex(&digest[..HASH_FINGERPRINT_LEN])
+    ))
+}
+
+/// Encodes bytes as lowercase hexadecimal string
+pub fn encode_hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        out.push(DIGITS[(byte >> 4) as usize] as char);
+        out.push(DIGITS[(byte & 0x0f) as usize] as char);
+    }
+    out
+}
+
+/// Encodes bytes as standard base64 string with padding
+pub fn encode_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
@@ -89,4 +648,273 @@ mod tests {
         assert_eq!(mask_pan_suffix("01234567890"), "*7890");
         assert_eq!(mask_pan_suffix("0123456789012345"), "*2345");
     }
+
+    #[test]
+    fn test_mask_non_ascii() {
+        assert_eq!(mask_all("Привет"), "******");
+        assert_eq!(mask_all("你好"), "**");
+        assert_eq!(mask_pan("Привет, мир!"), "Привет**мир!");
+        assert_eq!(mask_pan_suffix("Привет"), "*ивет");
+        assert_eq!(mask_pan_suffix("東京都千代田区"), "*千代田区");
+    }
+
+    #[cfg(feature = "graphemes")]
+    #[test]
+    fn test_mask_graphemes() {
+        assert_eq!(mask_all("e\u{301}"), "*");
+        assert_eq!(mask_all("🇷🇺🇯🇵"), "**");
+        assert_eq!(mask_pan_suffix("0123456e\u{301}"), "*456e\u{301}");
+    }
+
+    #[test]
+    fn test_mask_card() {
+        assert_eq!(mask_card("4111111111111111", false), "411111******1111");
+        assert_eq!(
+            mask_card("4111 1111 1111 1111", false),
+            "4111 11** **** 1111"
+        );
+        assert_eq!(mask_card("3782-822463-10005", false), "3782-82****-*0005");
+        assert_eq!(mask_card("4111 1111 1111 1111", true), "VISA ****1111");
+        assert_eq!(mask_card("5555555555554444", true), "MASTERCARD ****4444");
+        assert_eq!(mask_card("2221000000000009", true), "MASTERCARD ****0009");
+        assert_eq!(mask_card("378282246310005", true), "AMEX ****0005");
+        assert_eq!(mask_card("6011111111111117", true), "DISCOVER ****1117");
+        assert_eq!(mask_card("3530111333300000", true), "JCB ****0000");
+        assert_eq!(mask_card("2200000000000004", true), "MIR ****0004");
+        assert_eq!(mask_card("9000000000000001", true), "****0001");
+        // Luhn check fails
+        assert_eq!(mask_card("4111111111111112", false), "****************");
+        assert_eq!(mask_card("4111111111111112", true), "****************");
+        // Not a card number
+        assert_eq!(mask_card("0123456789012", false), "*************");
+        assert_eq!(
+            mask_card("order 4111111111111111", false),
+            "**********************"
+        );
+        assert_eq!(mask_card("411111111118", false), "************");
+        assert_eq!(mask_card("", true), "");
+    }
+
+    #[test]
+    fn test_luhn_valid() {
+        assert!(luhn_valid(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1, 3]));
+        assert!(!luhn_valid(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1, 0]));
+        assert!(luhn_valid(&[0]));
+    }
+
+    #[test]
+    fn test_mask_partial() {
+        assert_eq!(mask_partial("", 2, 3, '#'), "");
+        assert_eq!(mask_partial("01234", 2, 3, '#'), "#####");
+        assert_eq!(mask_partial("012345", 2, 3, '#'), "01#345");
+        assert_eq!(mask_partial("0123456789", 2, 3, '#'), "01#####789");
+        assert_eq!(mask_partial("0123456789", 0, 4, '*'), "******6789");
+        assert_eq!(mask_partial("0123456789", 0, 0, 'x'), "xxxxxxxxxx");
+        assert_eq!(mask_partial("+79161234567", 2, 2, '•'), "+7••••••••67");
+        assert_eq!(mask_partial("Привет", 1, 1, '*'), "П****т");
+        assert_eq!(mask_partial("0123", usize::MAX, usize::MAX, '*'), "****");
+    }
+
+    #[test]
+    fn test_mask_ip_subnet() {
+        assert_eq!(mask_ip_subnet("192.168.1.23"), "192.168.1.x");
+        assert_eq!(mask_ip_subnet("192.168.1.23:8080"), "192.168.1.x:8080");
+        assert_eq!(
+            mask_ip_subnet("2001:db8:85a3::8a2e:370:7334"),
+            "2001:db8:85a3:x:x:x:x:x"
+        );
+        assert_eq!(
+            mask_ip_subnet("[2001:db8:85a3::1]:443"),
+            "[2001:db8:85a3:x:x:x:x:x]:443"
+        );
+        assert_eq!(mask_ip_subnet("localhost"), "*********");
+        assert_eq!(mask_ip_subnet(""), "");
+    }
+
+    #[test]
+    fn test_mask_ip_port() {
+        assert_eq!(mask_ip_port("192.168.1.23"), "x.x.x.x");
+        assert_eq!(mask_ip_port("192.168.1.23:8080"), "x.x.x.x:8080");
+        assert_eq!(mask_ip_port("::1"), "x:x:x:x:x:x:x:x");
+        assert_eq!(mask_ip_port("[::1]:443"), "[x:x:x:x:x:x:x:x]:443");
+        assert_eq!(mask_ip_port("localhost:80"), "************");
+    }
+
+    #[test]
+    fn test_encode_hex() {
+        assert_eq!(encode_hex(b""), "");
+        assert_eq!(encode_hex(&[0x00, 0x0f, 0xa0, 0xff]), "000fa0ff");
+    }
+
+    #[test]
+    fn test_encode_base64() {
+        assert_eq!(encode_base64(b""), "");
+        assert_eq!(encode_base64(b"f"), "Zg==");
+        assert_eq!(encode_base64(b"fo"), "Zm8=");
+        assert_eq!(encode_base64(b"foo"), "Zm9v");
+        assert_eq!(encode_base64(b"foob"), "Zm9vYg==");
+        assert_eq!(encode_base64(b"fooba"), "Zm9vYmE=");
+        assert_eq!(encode_base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(encode_base64(&[0xfb, 0xff]), "+/8=");
+    }
+
+    #[test]
+    fn test_mask_email() {
+        assert_eq!(mask_email("john@example.com", true), "j***@example.com");
+        assert_eq!(mask_email("john@example.com", false), "j***@*******.com");
+        assert_eq!(
+            mask_email("j@mail.example.org", false),
+            "*@************.org"
+        );
+        assert_eq!(mask_email("j@example.com", true), "*@example.com");
+        assert_eq!(mask_email("иван@пример.рф", true), "и***@пример.рф");
+        assert_eq!(mask_email("a@b@example.com", true), "***************");
+        assert_eq!(mask_email("john@localhost", true), "**************");
+        assert_eq!(mask_email("john@example..com", true), "*****************");
+        assert_eq!(mask_email("@example.com", true), "************");
+        assert_eq!(
+            mask_email("john doe@example.com", true),
+            "********************"
+        );
+        assert_eq!(mask_email("", true), "");
+    }
+
+    #[test]
+    fn test_mask_length() {
+        assert_eq!(mask_length(""), "<redacted 0 chars>");
+        assert_eq!(mask_length("0"), "<redacted 1 char>");
+        assert_eq!(mask_length("0123456789012345"), "<redacted 16 chars>");
+        assert_eq!(mask_length("Привет"), "<redacted 6 chars>");
+    }
+
+    #[test]
+    fn test_mask_length_bucket() {
+        assert_eq!(mask_length_bucket(""), "<redacted 0 chars>");
+        assert_eq!(mask_length_bucket("0"), "<redacted ~1-2 chars>");
+        assert_eq!(mask_length_bucket("01"), "<redacted ~2-5 chars>");
+        assert_eq!(mask_length_bucket("01234"), "<redacted ~5-10 chars>");
+        assert_eq!(mask_length_bucket("0123456789"), "<redacted ~10-20 chars>");
+        assert_eq!(
+            mask_length_bucket("0123456789012345"),
+            "<redacted ~10-20 chars>"
+        );
+        assert_eq!(
+            mask_length_bucket(&"0".repeat(20)),
+            "<redacted ~20-50 chars>"
+        );
+        assert_eq!(
+            mask_length_bucket(&"0".repeat(1234)),
+            "<redacted ~1000-2000 chars>"
+        );
+    }
+
+    #[test]
+    fn test_mask_phone() {
+        assert_eq!(mask_phone("+7 (912) 345-67-67"), "+7 (***) ***-**-67");
+        assert_eq!(mask_phone("+1 415-555-0132"), "+1 ***-***-0132");
+        assert_eq!(mask_phone("+44 20 7946 0958"), "+44 ** **** 0958");
+        assert_eq!(mask_phone("+380 44 123 45 67"), "+380 ** *** ** 67");
+        assert_eq!(mask_phone("+79123456767"), "+7******6767");
+        assert_eq!(mask_phone("+4930123456"), "+49****3456");
+        assert_eq!(mask_phone("(912) 345-67-67"), "(***) ***-**-67");
+        assert_eq!(mask_phone("8.912.345.6767"), "*.***.***.6767");
+        assert_eq!(mask_phone("+7 123"), "******");
+        assert_eq!(mask_phone("call me"), "*******");
+        assert_eq!(
+            mask_phone("+7 912 345 67 67 ext 1"),
+            "**********************"
+        );
+        assert_eq!(mask_phone(""), "");
+    }
+
+    #[test]
+    fn test_mask_preserve_format() {
+        assert_eq!(mask_preserve_format("AB12-3456 7890"), "XX##-#### ####");
+        assert_eq!(
+            mask_preserve_format("GB82 WEST 1234 5698 7654 32"),
+            "XX## XXXX #### #### #### ##"
+        );
+        assert_eq!(
+            mask_preserve_format("f47ac10b-58cc-4372-a567-0e02b2c3d479"),
+            "x##xx##x-##xx-####-x###-#x##x#x#x###"
+        );
+        assert_eq!(mask_preserve_format("Иван_١٢"), "Xxxx_##");
+        assert_eq!(mask_preserve_format("pass🔑€©word"), "xxxx***xxxx");
+        assert_eq!(mask_preserve_format("a\u{a0}b\tc"), "x\u{a0}x\tx");
+        assert_eq!(mask_preserve_format(""), "");
+    }
+
+    #[test]
+    fn test_country_code_len() {
+        assert_eq!(country_code_len(b"1415"), 1);
+        assert_eq!(country_code_len(b"7912"), 1);
+        assert_eq!(country_code_len(b"4420"), 2);
+        assert_eq!(country_code_len(b"4930"), 2);
+        assert_eq!(country_code_len(b"8612"), 2);
+        assert_eq!(country_code_len(b"3804"), 3);
+        assert_eq!(country_code_len(b"3531"), 3);
+        assert_eq!(country_code_len(b"9715"), 3);
+        assert_eq!(country_code_len(b""), 3);
+    }
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn test_mask_hash() {
+        // Key registry is global, so all checks live in a single test.
+        clear_hash_key();
+        assert_eq!(mask_hash("secret"), "******");
+        set_hash_key("key");
+        let hash = mask_hash("secret");
+        assert_eq!(hash, "sha256:25cf3c44c8f3…");
+        assert_eq!(mask_hash("secret"), hash);
+        assert_ne!(mask_hash("secret2"), hash);
+        set_hash_key(b"other key".to_vec());
+        assert_ne!(mask_hash("secret"), hash);
+        clear_hash_key();
+    }
+
+    proptest! {
+        #[test]
+        fn prop_mask_never_panics(value in any::<String>()) {
+            for mask_type in [
+                MaskType::All,
+                MaskType::Pan,
+                MaskType::PanSuffix,
+                MaskType::Card { brand: false },
+                MaskType::Card { brand: true },
+                MaskType::Email {
+                    keep_domain: true,
+                    fallback: &MaskType::All,
+                },
+                MaskType::Email {
+                    keep_domain: false,
+                    fallback: &MaskType::PreserveFormat,
+                },
+                MaskType::Partial {
+                    keep_start: 2,
+                    keep_end: 3,
+                    mask_char: '#',
+                },
+                MaskType::IpSubnet,
+                MaskType::IpPort,
+                MaskType::Length,
+                MaskType::Hash,
+                MaskType::LengthBucket,
+                MaskType::Phone,
+                MaskType::PreserveFormat,
+            ] {
+                mask(&value, mask_type);
+            }
+        }
+
+        #[test]
+        fn prop_mask_preserves_length(value in any::<String>()) {
+            let sz = units(&value).count();
+            prop_assert_eq!(units(&mask_all(&value)).count(), sz);
+            prop_assert_eq!(units(&mask_pan(&value)).count(), sz);
+            prop_assert_eq!(units(&mask_phone(&value)).count(), sz);
+            prop_assert_eq!(units(&mask_preserve_format(&value)).count(), sz);
+            prop_assert!(units(&mask_pan_suffix(&value)).count() <= sz.min(5));
+        }
+    }
 }
@@ -0,0 +1,132 @@
+use std::{
+    cell::Cell,
+    marker::PhantomData,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// Environment variable that sets initial process-wide policy
+///
+/// Values `1`, `true`, `yes` and `reveal` (case-insensitive) select [`Policy::Reveal`].
+/// The variable is read once, when the policy is queried for the first time.
+pub const ENV_VAR: &str = "DEBOOG_REVEAL";
+
+/// Whether revealing masked values is allowed in this build
+///
+/// Revealing is only possible in builds with debug assertions, unless `reveal-in-release`
+/// feature is enabled.
+pub const REVEAL_ALLOWED: bool = cfg!(any(debug_assertions, feature = "reveal-in-release"));
+
+const UNSET: u8 = 0;
+const MASK: u8 = 1;
+const REVEAL: u8 = 2;
+
+static GLOBAL: AtomicU8 = AtomicU8::new(UNSET);
+
+thread_local! {
+    static SCOPED: Cell<Option<Policy>> = const { Cell::new(None) };
+}
+
+/// Masking policy
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Policy {
+    /// Mask values according to field attributes
+    #[default]
+    Mask,
+    /// Show real values of masked fields
+    ///
+    /// Types that rely on the default [`DeboogField`](crate::DeboogField) implementation
+    /// are still masked.
+    Reveal,
+}
+
+impl Policy {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            REVEAL => Policy::Reveal,
+            _ => Policy::Mask,
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            Policy::Mask => MASK,
+            Policy::Reveal => REVEAL,
+        }
+    }
+
+    fn from_env() -> Self {
+        match std::env::var(ENV_VAR) {
+            Ok(value)
+                if ["1", "true", "yes", "reveal"]
+                    .iter()
+                    .any(|v| value.trim().eq_ignore_ascii_case(v)) =>
+            {
+                Policy::Reveal
+            }
+            _ => Policy::Mask,
+        }
+    }
+}
+
+/// Sets process-wide masking policy
+///
+/// Has no effect on threads with a policy set by [`scoped`]. [`Policy::Reveal`] is ignored
+/// if [`REVEAL_ALLOWED`] is false.
+pub fn set(policy: Policy) {
+    GLOBAL.store(policy.to_u8(), Ordering::Relaxed);
+}
+
+/// Returns masking policy in effect for the current thread
+pub fn current() -> Policy {
+    if !REVEAL_ALLOWED {
+        return Policy::Mask;
+    }
+    if let Some(policy) = SCOPED.with(Cell::get) {
+        return policy;
+    }
+    match GLOBAL.load(Ordering::Relaxed) {
+        UNSET => {
+            let policy = Policy::from_env();
+            match GLOBAL.compare_exchange(
+                UNSET,
+                policy.to_u8(),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => policy,
+                Err(value) => Policy::from_u8(value),
+            }
+        }
+        value => Policy::from_u8(value),
+    }
+}
+
+/// Returns true if masked values should be shown as is
+pub fn reveals() -> bool {
+    current() == Policy::Reveal
+}
+
+/// Sets masking policy for the current thread until the returned guard is dropped
+///
+/// Takes precedence over the process-wide policy. Guards can be nested, dropping a guard
+/// restores the policy that was in effect when it was created.
+pub fn scoped(policy: Policy) -> PolicyGuard {
+    let previous = SCOPED.with(|scoped| scoped.replace(Some(policy)));
+    PolicyGuard {
+        previous,
+        _not_send: PhantomData,
+    }
+}
+
+/// Guard returned by [`scoped`]
+#[must_use = "policy is reset when the guard is dropped"]
+pub struct PolicyGuard {
+    previous: Option<Policy>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for PolicyGuard {
+    fn drop(&mut self) {
+        SCOPED.with(|scoped| scoped.set(self.previous));
+    }
+}
@@ -0,0 +1,388 @@
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+    ffi::{CStr, CString, OsStr, OsString},
+    num::Wrapping,
+    ops::Deref,
+    path::{Path, PathBuf},
+    pin::Pin,
+    rc::Rc,
+    sync::{Arc, Mutex, RwLock, TryLockError},
+};
+
+use ::serde::{
+    ser::{Error, SerializeTuple},
+    Serialize, Serializer,
+};
+
+use crate::{
+    field::{
+        DeboogField, DeboogMap, DebugWith, Masked, MaskedBytes, MaskedEntries, MaskedKeys,
+        MaskedValues,
+    },
+    masking::*,
+    policy,
+};
+
+#[doc(hidden)]
+pub use ::serde as export;
+
+/// Trait used to produce masked serialized values
+///
+/// Masked values are serialized as strings, while containers keep their structure.
+pub trait SerializeMasked {
+    /// Serializes masked value
+    #[allow(unused_variables)]
+    fn serialize_masked<S: Serializer>(
+        &self,
+        serializer: S,
+        mask_type: MaskType,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(HIDE_STR)
+    }
+}
+
+impl<'a, T: DeboogField + SerializeMasked> Serialize for Masked<'a, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.mask_type() {
+            Some(mask_type) => self.value().serialize_masked(serializer, mask_type),
+            None if policy::reveals() => self.value().serialize_masked(serializer, MaskType::All),
+            None => serializer.serialize_str(self.placeholder()),
+        }
+    }
+}
+
+impl<'a, T: DeboogMap> Serialize for MaskedKeys<'a, T>
+where
+    T::Key: SerializeMasked,
+    T::Value: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.value
+                .map_entries()
+                .map(|(key, value)| (AlwaysMasked(key, self.mask_type), value)),
+        )
+    }
+}
+
+impl<'a, T: DeboogMap> Serialize for MaskedValues<'a, T>
+where
+    T::Key: Serialize,
+    T::Value: SerializeMasked,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.value
+                .map_entries()
+                .map(|(key, value)| (key, AlwaysMasked(value, self.mask_type))),
+        )
+    }
+}
+
+impl<'a, T: DeboogMap> Serialize for MaskedEntries<'a, T>
+where
+    T::Key: SerializeMasked,
+    T::Value: SerializeMasked,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.value.map_entries().map(|(key, value)| {
+            (
+                AlwaysMasked(key, self.keys),
+                AlwaysMasked(value, self.values),
+            )
+        }))
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Serialize for MaskedBytes<'a, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:?}", self))
+    }
+}
+
+impl<'a, T: ?Sized> Serialize for DebugWith<'a, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:?}", self))
+    }
+}
+
+/// Serialize adapter that always masks the value
+struct AlwaysMasked<'a, T: ?Sized>(&'a T, MaskType);
+
+impl<'a, T: SerializeMasked + ?Sized> Serialize for AlwaysMasked<'a, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_masked(serializer, self.1)
+    }
+}
+
+macro_rules! str_impl {
+    ($t:ty, $value:ident => $as_str:expr) => {
+        impl SerializeMasked for $t {
+            fn serialize_masked<S: Serializer>(
+                &self,
+                serializer: S,
+                mask_type: MaskType,
+            ) -> Result<S::Ok, S::Error> {
+                let $value = self;
+                serializer.serialize_str(&mask(&$as_str, mask_type))
+            }
+        }
+    };
+}
+
+impl<T: SerializeMasked + ?Sized> SerializeMasked for &T {
+    fn serialize_masked<S: Serializer>(
+        &self,
+        serializer: S,
+        mask_type: MaskType,
+    ) -> Result<S::Ok, S::Error> {
+        (**self).serialize_masked(serializer, mask_type)
+    }
+}
+
+macro_rules! deref_impl {
+    ($t:ident) => {
+        impl<T: SerializeMasked + ?Sized> SerializeMasked for $t<T> {
+            fn serialize_masked<S: Serializer>(
+                &self,
+                serializer: S,
+                mask_type: MaskType,
+            ) -> Result<S::Ok, S::Error> {
+                (**self).serialize_masked(serializer, mask_type)
+            }
+        }
+    };
+}
+
+impl<'a, T: SerializeMasked + ToOwned + ?Sized> SerializeMasked for Cow<'a, T> {
+    fn serialize_masked<S: Serializer>(
+        &self,
+        serializer: S,
+        mask_type: MaskType,
+    ) -> Result<S::Ok, S::Error> {
+        (**self).serialize_masked(serializer, mask_type)
+    }
+}
+
+impl<P: Deref> SerializeMasked for Pin<P>
+where
+    P::Target: SerializeMasked,
+{
+    fn serialize_masked<S: Serializer>(
+        &self,
+        serializer: S,
+        mask_type: MaskType,
+    ) -> Result<S::Ok, S::Error> {
+        (**self).serialize_masked(serializer, mask_type)
+    }
+}
+
+impl<T: SerializeMasked> SerializeMasked for Wrapping<T> {
+    fn serialize_masked<S: Serializer>(
+        &self,
+        serializer: S,
+        mask_type: MaskType,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize_masked(serializer, mask_type)
+    }
+}
+
+impl<T: SerializeMasked> SerializeMasked for Reverse<T> {
+    fn serialize_masked<S: Serializer>(
+        &self,
+        serializer: S,
+        mask_type: MaskType,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize_masked(serializer, mask_type)
+    }
+}
+
+impl<T: SerializeMasked + ?Sized> SerializeMasked for RefCell<T> {
+    fn serialize_masked<S: Serializer>(
+        &self,
+        serializer: S,
+        mask_type: MaskType,
+    ) -> Result<S::Ok, S::Error> {
+        match self.try_borrow() {
+            Ok(value) => value.serialize_masked(serializer, mask_type),
+            Err(_) => Err(S::Error::custom("already mutably borrowed")),
+        }
+    }
+}
+
+impl<T: SerializeMasked + ?Sized> SerializeMasked for Mutex<T> {
+    fn serialize_masked<S: Serializer>(
+        &self,
+        serializer: S,
+        mask_type: MaskType,
+    ) -> Result<S::Ok, S::Error> {
+        // Blocking here would deadlock if the lock is held by the serializing thread
+        match self.try_lock() {
+            Ok(value) => value.serialize_masked(serializer, mask_type),
+            Err(TryLockError::WouldBlock) => Err(S::Error::custom("already locked")),
+            Err(TryLockError::Poisoned(_)) => {
+                Err(S::Error::custom("lock poison error while serializing"))
+            }
+        }
+    }
+}
+
+impl<T: SerializeMasked + ?Sized> SerializeMasked for RwLock<T> {
+    fn serialize_masked<S: Serializer>(
+        &self,
+        serializer: S,
+        mask_type: MaskType,
+    ) -> Result<S::Ok, S::Error> {
+        match self.try_read() {
+            Ok(value) => value.serialize_masked(serializer, mask_type),
+            Err(TryLockError::WouldBlock) => Err(S::Error::custom("already locked")),
+            Err(TryLockError::Poisoned(_)) => {
+                Err(S::Error::custom("lock poison error while serializing"))
+            }
+        }
+    }
+}
+
+impl<T: SerializeMasked> SerializeMasked for Option<T> {
+    fn serialize_masked<S: Serializer>(
+        &self,
+        serializer: S,
+        mask_type: MaskType,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(ref value) => serializer.serialize_some(&AlwaysMasked(value, mask_type)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<T: SerializeMasked, E: SerializeMasked> SerializeMasked for Result<T, E> {
+    fn serialize_masked<S: Serializer>(
+        &self,
+        serializer: S,
+        mask_type: MaskType,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Ok(ref value) => serializer.serialize_newtype_variant(
+                "Result",
+                0,
+                "Ok",
+                &AlwaysMasked(value, mask_type),
+            ),
+            Err(ref error) => serializer.serialize_newtype_variant(
+                "Result",
+                1,
+                "Err",
+                &AlwaysMasked(error, mask_type),
+            ),
+        }
+    }
+}
+
+impl<T: SerializeMasked> SerializeMasked for [T] {
+    fn serialize_masked<S: Serializer>(
+        &self,
+        serializer: S,
+        mask_type: MaskType,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(|item| AlwaysMasked(item, mask_type)))
+    }
+}
+
+impl<T: SerializeMasked, const N: usize> SerializeMasked for [T; N] {
+    fn serialize_masked<S: Serializer>(
+        &self,
+        serializer: S,
+        mask_type: MaskType,
+    ) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for item in self {
+            tuple.serialize_element(&AlwaysMasked(item, mask_type))?;
+        }
+        tuple.end()
+    }
+}
+
+macro_rules! seq_impl {
+    ($t:ident $(, $s:ident)?) => {
+        impl<T: SerializeMasked $(, $s)?> SerializeMasked for $t<T $(, $s)?> {
+            fn serialize_masked<S: Serializer>(
+                &self,
+                serializer: S,
+                mask_type: MaskType,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq(self.iter().map(|item| AlwaysMasked(item, mask_type)))
+            }
+        }
+    };
+}
+
+macro_rules! map_impl {
+    ($t:ident $(, $s:ident)?) => {
+        impl<K: SerializeMasked, V: SerializeMasked $(, $s)?> SerializeMasked
+            for $t<K, V $(, $s)?>
+        {
+            fn serialize_masked<S: Serializer>(
+                &self,
+                serializer: S,
+                mask_type: MaskType,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.collect_map(self.iter().map(|(key, value)| {
+                    (AlwaysMasked(key, mask_type), AlwaysMasked(value, mask_type))
+                }))
+            }
+        }
+    };
+}
+
+macro_rules! tuple_impl {
+    ($len:literal => $($name:ident)+) => {
+        impl<$($name: SerializeMasked),+> SerializeMasked for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn serialize_masked<S: Serializer>(
+                &self,
+                serializer: S,
+                mask_type: MaskType,
+            ) -> Result<S::Ok, S::Error> {
+                let ($(ref $name,)+) = *self;
+                let mut tuple = serializer.serialize_tuple($len)?;
+                $(tuple.serialize_element(&AlwaysMasked($name, mask_type))?;)+
+                tuple.end()
+            }
+        }
+    };
+}
+
+macro_rules! display_impl {
+    ($t:ty) => {
+        impl SerializeMasked for $t {
+            fn serialize_masked<S: Serializer>(
+                &self,
+                serializer: S,
+                mask_type: MaskType,
+            ) -> Result<S::Ok, S::Error> {
+                if policy::reveals() {
+                    return self.serialize(serializer);
+                }
+                serializer.serialize_str(&mask(&self.to_string(), mask_type))
+            }
+        }
+    };
+}
+
+std_types! {
+    str: str_impl,
+    char: str_impl,
+    deref: deref_impl,
+    list: seq_impl,
+    set: seq_impl,
+    map: map_impl,
+    tuple: tuple_impl,
+    bool: display_impl,
+    number: display_impl,
+    non_zero: display_impl,
+    address: display_impl,
+}
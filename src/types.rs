@@ -0,0 +1,106 @@
+/// Invokes implementation macros of a masking backend for all supported std types
+///
+/// Types are grouped by the way they are masked, and each backend passes its own macro for every
+/// group, optionally with extra arguments that are appended to the type:
+///
+/// - `str`: `$str!(Type, value => string conversion)` for text types;
+/// - `char`: same for `char`, which is quoted differently in `Debug` output;
+/// - `deref`: `$deref!(Box)` for smart pointers;
+/// - `list` and `set`: `$list!(Vec)`, `$set!(HashSet, H)` for collections;
+/// - `map`: `$map!(HashMap, H)` for maps;
+/// - `tuple`: `$tuple!(2 => A B)` for tuples;
+/// - `bool`, `number`, `non_zero` and `address`: `$number!(i8)` for types masked as their
+///   `Display` output.
+///
+/// New std types should be added here, so that all backends support them.
+macro_rules! std_types {
+    (
+        str: $str:ident $(($($str_arg:tt)*))?,
+        char: $char:ident $(($($char_arg:tt)*))?,
+        deref: $deref:ident,
+        list: $list:ident,
+        set: $set:ident,
+        map: $map:ident,
+        tuple: $tuple:ident,
+        bool: $bool:ident $(($($bool_arg:tt)*))?,
+        number: $number:ident $(($($number_arg:tt)*))?,
+        non_zero: $non_zero:ident $(($($non_zero_arg:tt)*))?,
+        address: $address:ident $(($($address_arg:tt)*))?,
+    ) => {
+        $str!(String, value => value $(, $($str_arg)*)?);
+        $str!(str, value => value $(, $($str_arg)*)?);
+        $str!(Path, value => value.to_string_lossy() $(, $($str_arg)*)?);
+        $str!(PathBuf, value => value.to_string_lossy() $(, $($str_arg)*)?);
+        $str!(OsStr, value => value.to_string_lossy() $(, $($str_arg)*)?);
+        $str!(OsString, value => value.to_string_lossy() $(, $($str_arg)*)?);
+        $str!(CStr, value => value.to_string_lossy() $(, $($str_arg)*)?);
+        $str!(CString, value => value.to_string_lossy() $(, $($str_arg)*)?);
+
+        $char!(char, value => value.encode_utf8(&mut [0; 4]) $(, $($char_arg)*)?);
+
+        $deref!(Box);
+        $deref!(Rc);
+        $deref!(Arc);
+
+        $list!(Vec);
+        $list!(VecDeque);
+        $list!(LinkedList);
+        $list!(BinaryHeap);
+
+        $set!(HashSet, H);
+        $set!(BTreeSet);
+
+        $map!(HashMap, H);
+        $map!(BTreeMap);
+
+        $tuple!(1 => A);
+        $tuple!(2 => A B);
+        $tuple!(3 => A B C);
+        $tuple!(4 => A B C D);
+        $tuple!(5 => A B C D E);
+        $tuple!(6 => A B C D E F);
+        $tuple!(7 => A B C D E F G);
+        $tuple!(8 => A B C D E F G H);
+        $tuple!(9 => A B C D E F G H I);
+        $tuple!(10 => A B C D E F G H I J);
+        $tuple!(11 => A B C D E F G H I J K);
+        $tuple!(12 => A B C D E F G H I J K L);
+
+        $bool!(bool $(, $($bool_arg)*)?);
+
+        $number!(i8 $(, $($number_arg)*)?);
+        $number!(i16 $(, $($number_arg)*)?);
+        $number!(i32 $(, $($number_arg)*)?);
+        $number!(i64 $(, $($number_arg)*)?);
+        $number!(i128 $(, $($number_arg)*)?);
+        $number!(isize $(, $($number_arg)*)?);
+        $number!(u8 $(, $($number_arg)*)?);
+        $number!(u16 $(, $($number_arg)*)?);
+        $number!(u32 $(, $($number_arg)*)?);
+        $number!(u64 $(, $($number_arg)*)?);
+        $number!(u128 $(, $($number_arg)*)?);
+        $number!(usize $(, $($number_arg)*)?);
+        $number!(f32 $(, $($number_arg)*)?);
+        $number!(f64 $(, $($number_arg)*)?);
+
+        $non_zero!(std::num::NonZeroI8 $(, $($non_zero_arg)*)?);
+        $non_zero!(std::num::NonZeroI16 $(, $($non_zero_arg)*)?);
+        $non_zero!(std::num::NonZeroI32 $(, $($non_zero_arg)*)?);
+        $non_zero!(std::num::NonZeroI64 $(, $($non_zero_arg)*)?);
+        $non_zero!(std::num::NonZeroI128 $(, $($non_zero_arg)*)?);
+        $non_zero!(std::num::NonZeroIsize $(, $($non_zero_arg)*)?);
+        $non_zero!(std::num::NonZeroU8 $(, $($non_zero_arg)*)?);
+        $non_zero!(std::num::NonZeroU16 $(, $($non_zero_arg)*)?);
+        $non_zero!(std::num::NonZeroU32 $(, $($non_zero_arg)*)?);
+        $non_zero!(std::num::NonZeroU64 $(, $($non_zero_arg)*)?);
+        $non_zero!(std::num::NonZeroU128 $(, $($non_zero_arg)*)?);
+        $non_zero!(std::num::NonZeroUsize $(, $($non_zero_arg)*)?);
+
+        $address!(std::net::IpAddr $(, $($address_arg)*)?);
+        $address!(std::net::Ipv4Addr $(, $($address_arg)*)?);
+        $address!(std::net::Ipv6Addr $(, $($address_arg)*)?);
+        $address!(std::net::SocketAddr $(, $($address_arg)*)?);
+        $address!(std::net::SocketAddrV4 $(, $($address_arg)*)?);
+        $address!(std::net::SocketAddrV6 $(, $($address_arg)*)?);
+    };
+}
@@ -0,0 +1,467 @@
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+    ffi::{CStr, CString, OsStr, OsString},
+    num::Wrapping,
+    ops::Deref,
+    path::{Path, PathBuf},
+    pin::Pin,
+    rc::Rc,
+    sync::{Arc, Mutex, RwLock, TryLockError},
+};
+
+use ::valuable::{
+    EnumDef, Enumerable, Fields, Listable, Mappable, Tuplable, TupleDef, Valuable, Value, Variant,
+    VariantDef, Visit,
+};
+
+use crate::{
+    field::{
+        DeboogField, DeboogMap, DebugWith, Masked, MaskedBytes, MaskedEntries, MaskedKeys,
+        MaskedValues,
+    },
+    masking::*,
+    policy,
+};
+
+#[doc(hidden)]
+pub use ::valuable as export;
+
+/// Masked value of a field, recorded in place of the original one
+///
+/// Masked values are recorded as strings, while collections and tuples keep their structure.
+pub enum MaskedValue<'a> {
+    /// Value that is recorded as is
+    Plain(Value<'a>),
+    /// Masked string
+    String(String),
+    /// List of masked values
+    List(Vec<MaskedValue<'a>>),
+    /// Map with masked keys and/or values
+    Map(Vec<(MaskedValue<'a>, MaskedValue<'a>)>),
+    /// Tuple of masked values
+    Tuple(Vec<MaskedValue<'a>>),
+    /// `Ok` or `Err` variant of a result with masked contents
+    Result(Result<Box<MaskedValue<'a>>, Box<MaskedValue<'a>>>),
+}
+
+impl<'a> MaskedValue<'a> {
+    /// Converts into a value that doesn't borrow from the original field
+    ///
+    /// Used for values behind locks and cells, which can't be borrowed past the guard.
+    fn into_owned(self) -> MaskedValue<'static> {
+        match self {
+            MaskedValue::Plain(value) => owned_value(value),
+            MaskedValue::String(value) => MaskedValue::String(value),
+            MaskedValue::List(items) => {
+                MaskedValue::List(items.into_iter().map(MaskedValue::into_owned).collect())
+            }
+            MaskedValue::Map(entries) => MaskedValue::Map(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                    .collect(),
+            ),
+            MaskedValue::Tuple(items) => {
+                MaskedValue::Tuple(items.into_iter().map(MaskedValue::into_owned).collect())
+            }
+            MaskedValue::Result(result) => MaskedValue::Result(match result {
+                Ok(value) => Ok(Box::new(value.into_owned())),
+                Err(error) => Err(Box::new(error.into_owned())),
+            }),
+        }
+    }
+}
+
+/// Copies primitive values, and records borrowed ones as strings
+fn owned_value(value: Value<'_>) -> MaskedValue<'static> {
+    MaskedValue::Plain(match value {
+        Value::Bool(value) => Value::Bool(value),
+        Value::Char(value) => Value::Char(value),
+        Value::F32(value) => Value::F32(value),
+        Value::F64(value) => Value::F64(value),
+        Value::I8(value) => Value::I8(value),
+        Value::I16(value) => Value::I16(value),
+        Value::I32(value) => Value::I32(value),
+        Value::I64(value) => Value::I64(value),
+        Value::I128(value) => Value::I128(value),
+        Value::Isize(value) => Value::Isize(value),
+        Value::U8(value) => Value::U8(value),
+        Value::U16(value) => Value::U16(value),
+        Value::U32(value) => Value::U32(value),
+        Value::U64(value) => Value::U64(value),
+        Value::U128(value) => Value::U128(value),
+        Value::Usize(value) => Value::Usize(value),
+        Value::Unit => Value::Unit,
+        Value::String(value) => return MaskedValue::String(value.to_owned()),
+        Value::Path(value) => return MaskedValue::String(value.to_string_lossy().into_owned()),
+        value => return MaskedValue::String(format!("{:?}", value)),
+    })
+}
+
+static RESULT_VARIANTS: &[VariantDef<'static>] = &[
+    VariantDef::new("Ok", Fields::Unnamed(1)),
+    VariantDef::new("Err", Fields::Unnamed(1)),
+];
+
+impl<'a> Valuable for MaskedValue<'a> {
+    fn as_value(&self) -> Value<'_> {
+        match self {
+            MaskedValue::Plain(value) => *value,
+            MaskedValue::String(value) => Value::String(value),
+            MaskedValue::List(_) => Value::Listable(self),
+            MaskedValue::Map(_) => Value::Mappable(self),
+            MaskedValue::Tuple(_) => Value::Tuplable(self),
+            MaskedValue::Result(_) => Value::Enumerable(self),
+        }
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        match self {
+            MaskedValue::Plain(value) => value.visit(visit),
+            MaskedValue::String(_) => visit.visit_value(self.as_value()),
+            MaskedValue::List(items) => {
+                for item in items {
+                    visit.visit_value(item.as_value());
+                }
+            }
+            MaskedValue::Map(entries) => {
+                for (key, value) in entries {
+                    visit.visit_entry(key.as_value(), value.as_value());
+                }
+            }
+            MaskedValue::Tuple(items) => {
+                let values: Vec<Value<'_>> = items.iter().map(Valuable::as_value).collect();
+                visit.visit_unnamed_fields(&values);
+            }
+            MaskedValue::Result(Ok(value) | Err(value)) => {
+                visit.visit_unnamed_fields(&[value.as_value()]);
+            }
+        }
+    }
+}
+
+impl<'a> Listable for MaskedValue<'a> {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            MaskedValue::List(items) => (items.len(), Some(items.len())),
+            _ => (0, Some(0)),
+        }
+    }
+}
+
+impl<'a> Mappable for MaskedValue<'a> {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            MaskedValue::Map(entries) => (entries.len(), Some(entries.len())),
+            _ => (0, Some(0)),
+        }
+    }
+}
+
+impl<'a> Enumerable for MaskedValue<'a> {
+    fn definition(&self) -> EnumDef<'_> {
+        EnumDef::new_static("Result", RESULT_VARIANTS)
+    }
+
+    fn variant(&self) -> Variant<'_> {
+        match self {
+            MaskedValue::Result(Err(_)) => Variant::Static(&RESULT_VARIANTS[1]),
+            _ => Variant::Static(&RESULT_VARIANTS[0]),
+        }
+    }
+}
+
+impl<'a> Tuplable for MaskedValue<'a> {
+    fn definition(&self) -> TupleDef {
+        match self {
+            MaskedValue::Tuple(items) => TupleDef::new_static(items.len()),
+            _ => TupleDef::new_static(0),
+        }
+    }
+}
+
+/// Trait used to produce masked recorded values
+pub trait ValuableMasked {
+    /// Returns masked value
+    #[allow(unused_variables)]
+    fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+        MaskedValue::String(HIDE_STR.to_owned())
+    }
+}
+
+/// Conversion of field wrappers to masked values
+#[doc(hidden)]
+pub trait MaskedField<'a> {
+    /// Returns masked value of the wrapped field
+    fn to_masked_value(&self) -> MaskedValue<'a>;
+}
+
+impl<'a, T: DeboogField + ValuableMasked> MaskedField<'a> for Masked<'a, T> {
+    fn to_masked_value(&self) -> MaskedValue<'a> {
+        match self.mask_type() {
+            Some(mask_type) => self.value().masked_value(mask_type),
+            None if policy::reveals() => self.value().masked_value(MaskType::All),
+            None => MaskedValue::String(self.placeholder().to_owned()),
+        }
+    }
+}
+
+impl<'a, T: DeboogMap> MaskedField<'a> for MaskedKeys<'a, T>
+where
+    T::Key: ValuableMasked,
+    T::Value: Valuable,
+{
+    fn to_masked_value(&self) -> MaskedValue<'a> {
+        MaskedValue::Map(
+            self.value
+                .map_entries()
+                .map(|(key, value)| {
+                    (
+                        key.masked_value(self.mask_type),
+                        MaskedValue::Plain(value.as_value()),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<'a, T: DeboogMap> MaskedField<'a> for MaskedValues<'a, T>
+where
+    T::Key: Valuable,
+    T::Value: ValuableMasked,
+{
+    fn to_masked_value(&self) -> MaskedValue<'a> {
+        MaskedValue::Map(
+            self.value
+                .map_entries()
+                .map(|(key, value)| {
+                    (
+                        MaskedValue::Plain(key.as_value()),
+                        value.masked_value(self.mask_type),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<'a, T: DeboogMap> MaskedField<'a> for MaskedEntries<'a, T>
+where
+    T::Key: ValuableMasked,
+    T::Value: ValuableMasked,
+{
+    fn to_masked_value(&self) -> MaskedValue<'a> {
+        MaskedValue::Map(
+            self.value
+                .map_entries()
+                .map(|(key, value)| (key.masked_value(self.keys), value.masked_value(self.values)))
+                .collect(),
+        )
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> MaskedField<'a> for MaskedBytes<'a, T> {
+    fn to_masked_value(&self) -> MaskedValue<'a> {
+        MaskedValue::String(format!("{:?}", self))
+    }
+}
+
+impl<'a, T: ?Sized> MaskedField<'a> for DebugWith<'a, T> {
+    fn to_masked_value(&self) -> MaskedValue<'a> {
+        MaskedValue::String(format!("{:?}", self))
+    }
+}
+
+macro_rules! str_impl {
+    ($t:ty, $value:ident => $as_str:expr) => {
+        impl ValuableMasked for $t {
+            fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+                let $value = self;
+                MaskedValue::String(mask(&$as_str, mask_type))
+            }
+        }
+    };
+}
+
+impl<T: ValuableMasked + ?Sized> ValuableMasked for &T {
+    fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+        (**self).masked_value(mask_type)
+    }
+}
+
+macro_rules! deref_impl {
+    ($t:ident) => {
+        impl<T: ValuableMasked + ?Sized> ValuableMasked for $t<T> {
+            fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+                (**self).masked_value(mask_type)
+            }
+        }
+    };
+}
+
+impl<'a, T: ValuableMasked + ToOwned + ?Sized> ValuableMasked for Cow<'a, T> {
+    fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+        (**self).masked_value(mask_type)
+    }
+}
+
+impl<P: Deref> ValuableMasked for Pin<P>
+where
+    P::Target: ValuableMasked,
+{
+    fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+        (**self).masked_value(mask_type)
+    }
+}
+
+impl<T: ValuableMasked> ValuableMasked for Wrapping<T> {
+    fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+        self.0.masked_value(mask_type)
+    }
+}
+
+impl<T: ValuableMasked> ValuableMasked for Reverse<T> {
+    fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+        self.0.masked_value(mask_type)
+    }
+}
+
+impl<T: ValuableMasked + ?Sized> ValuableMasked for RefCell<T> {
+    fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+        match self.try_borrow() {
+            Ok(value) => value.masked_value(mask_type).into_owned(),
+            Err(_) => MaskedValue::String("<borrowed>".to_owned()),
+        }
+    }
+}
+
+impl<T: ValuableMasked + ?Sized> ValuableMasked for Mutex<T> {
+    fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+        // Blocking here would deadlock if the lock is held by the recording thread
+        match self.try_lock() {
+            Ok(value) => value.masked_value(mask_type).into_owned(),
+            Err(TryLockError::Poisoned(err)) => err.get_ref().masked_value(mask_type).into_owned(),
+            Err(TryLockError::WouldBlock) => MaskedValue::String("<locked>".to_owned()),
+        }
+    }
+}
+
+impl<T: ValuableMasked + ?Sized> ValuableMasked for RwLock<T> {
+    fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+        match self.try_read() {
+            Ok(value) => value.masked_value(mask_type).into_owned(),
+            Err(TryLockError::Poisoned(err)) => err.get_ref().masked_value(mask_type).into_owned(),
+            Err(TryLockError::WouldBlock) => MaskedValue::String("<locked>".to_owned()),
+        }
+    }
+}
+
+impl<T: ValuableMasked> ValuableMasked for Option<T> {
+    fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+        match self {
+            Some(ref value) => value.masked_value(mask_type),
+            None => MaskedValue::Plain(Value::Unit),
+        }
+    }
+}
+
+impl<T: ValuableMasked, E: ValuableMasked> ValuableMasked for Result<T, E> {
+    fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+        MaskedValue::Result(match self {
+            Ok(ref value) => Ok(Box::new(value.masked_value(mask_type))),
+            Err(ref error) => Err(Box::new(error.masked_value(mask_type))),
+        })
+    }
+}
+
+impl<T: ValuableMasked> ValuableMasked for [T] {
+    fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+        MaskedValue::List(
+            self.iter()
+                .map(|item| item.masked_value(mask_type))
+                .collect(),
+        )
+    }
+}
+
+impl<T: ValuableMasked, const N: usize> ValuableMasked for [T; N] {
+    fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+        self[..].masked_value(mask_type)
+    }
+}
+
+macro_rules! list_impl {
+    ($t:ident $(, $s:ident)?) => {
+        impl<T: ValuableMasked $(, $s)?> ValuableMasked for $t<T $(, $s)?> {
+            fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+                MaskedValue::List(self.iter().map(|item| item.masked_value(mask_type)).collect())
+            }
+        }
+    };
+}
+
+macro_rules! map_impl {
+    ($t:ident $(, $s:ident)?) => {
+        impl<K: ValuableMasked, V: ValuableMasked $(, $s)?> ValuableMasked for $t<K, V $(, $s)?> {
+            fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+                MaskedValue::Map(
+                    self.iter()
+                        .map(|(key, value)| {
+                            (key.masked_value(mask_type), value.masked_value(mask_type))
+                        })
+                        .collect(),
+                )
+            }
+        }
+    };
+}
+
+macro_rules! tuple_impl {
+    ($len:literal => $($name:ident)+) => {
+        impl<$($name: ValuableMasked),+> ValuableMasked for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+                let ($(ref $name,)+) = *self;
+                MaskedValue::Tuple(vec![$($name.masked_value(mask_type)),+])
+            }
+        }
+    };
+}
+
+macro_rules! display_impl {
+    ($t:ty) => {
+        impl ValuableMasked for $t {
+            fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+                if policy::reveals() {
+                    return MaskedValue::Plain(self.as_value());
+                }
+                MaskedValue::String(mask(&self.to_string(), mask_type))
+            }
+        }
+    };
+    ($t:ty, display) => {
+        impl ValuableMasked for $t {
+            fn masked_value(&self, mask_type: MaskType) -> MaskedValue<'_> {
+                MaskedValue::String(mask(&self.to_string(), mask_type))
+            }
+        }
+    };
+}
+
+std_types! {
+    str: str_impl,
+    char: str_impl,
+    deref: deref_impl,
+    list: list_impl,
+    set: list_impl,
+    map: map_impl,
+    tuple: tuple_impl,
+    bool: display_impl,
+    number: display_impl,
+    non_zero: display_impl(display),
+    address: display_impl(display),
+}
@@ -9,6 +9,7 @@ fn unit_struct() {
 
 #[test]
 fn tuple_struct() {
+    #[allow(dead_code)]
     #[derive(Deboog)]
     struct Tuple(i32, &'static str);
     let our = Tuple(123, "test");
@@ -17,8 +18,10 @@ fn tuple_struct() {
 
 #[test]
 fn nested_tuple_struct() {
+    #[allow(dead_code)]
     #[derive(Deboog)]
     struct Inner(i32);
+    #[allow(dead_code)]
     #[derive(Deboog)]
     struct Outer(Inner);
     let our = Outer(Inner(123));
@@ -57,3 +60,21 @@ fn nested_normal_struct() {
         r#"Outer { inner: Inner { a: 123, b: "test" } }"#
     );
 }
+
+#[test]
+fn raw_identifiers() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Struct {
+        r#type: i32,
+    }
+    #[allow(dead_code, non_camel_case_types)]
+    #[derive(Deboog)]
+    enum Enum {
+        r#struct { r#fn: i32 },
+    }
+    let our = Struct { r#type: 1 };
+    assert_eq!(format!("{:?}", our), r#"Struct { type: 1 }"#);
+    let our = Enum::r#struct { r#fn: 2 };
+    assert_eq!(format!("{:?}", our), r#"struct { fn: 2 }"#);
+}
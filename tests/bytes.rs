@@ -0,0 +1,76 @@
+use deboog::Deboog;
+
+#[test]
+fn bytes_unmasked() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(bytes = "hex")]
+        hex: Vec<u8>,
+        #[deboog(bytes = "base64")]
+        base64: [u8; 3],
+        #[deboog(bytes = "length")]
+        length: Vec<u8>,
+    }
+    let our = Test {
+        hex: vec![0xde, 0xad, 0xbe, 0xef],
+        base64: *b"foo",
+        length: vec![0; 32],
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        "Test { hex: deadbeef, base64: Zm9v, length: [u8; 32 bytes] }"
+    );
+}
+
+#[test]
+fn bytes_masked() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(bytes = "hex", mask(keep_start = 2, keep_end = 2))]
+        hex: Vec<u8>,
+        #[deboog(bytes = "base64", mask = "all")]
+        base64: &'static [u8],
+        #[deboog(bytes = "length", mask = "all")]
+        length: Box<[u8]>,
+        #[deboog(bytes = "hex", mask = "hidden")]
+        hidden: Vec<u8>,
+    }
+    let our = Test {
+        hex: vec![0xde, 0xad, 0xbe, 0xef],
+        base64: b"foobar",
+        length: vec![0; 16].into(),
+        hidden: vec![0; 16],
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        "Test { hex: de****ef, base64: ********, length: [u8; 16 bytes], hidden: *** }"
+    );
+}
+
+#[test]
+fn bytes_container_mask() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(mask = "all")]
+    enum Key {
+        Symmetric(#[deboog(bytes = "hex")] Vec<u8>),
+        Pair {
+            #[deboog(bytes = "length")]
+            private: Vec<u8>,
+            #[deboog(bytes = "hex", unmask)]
+            public: Vec<u8>,
+        },
+    }
+    let our = Key::Symmetric(vec![1, 2]);
+    assert_eq!(format!("{:?}", our), "Symmetric(****)");
+    let our = Key::Pair {
+        private: vec![0; 32],
+        public: vec![1, 2],
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        "Pair { private: [u8; 32 bytes], public: 0102 }"
+    );
+}
@@ -0,0 +1,190 @@
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+
+use deboog::Deboog;
+
+#[test]
+fn mask_all_slice_and_array() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all")]
+        slice: &'static [&'static str],
+        #[deboog(mask = "all")]
+        array: [i32; 3],
+    }
+    let our = Test {
+        slice: &["123", "4567"],
+        array: [1, 22, 333],
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { slice: ["***", "****"], array: [*, **, ***] }"#
+    );
+}
+
+#[test]
+fn mask_all_lists() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all")]
+        deque: VecDeque<&'static str>,
+        #[deboog(mask = "all")]
+        list: LinkedList<i32>,
+        #[deboog(mask = "all")]
+        heap: BinaryHeap<i32>,
+    }
+    let our = Test {
+        deque: VecDeque::from(["123", "4567"]),
+        list: LinkedList::from([1, 22]),
+        heap: BinaryHeap::from([333]),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { deque: ["***", "****"], list: [*, **], heap: [***] }"#
+    );
+}
+
+#[test]
+fn mask_all_sets() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all")]
+        hash: HashSet<&'static str>,
+        #[deboog(mask = "pan_suffix")]
+        btree: BTreeSet<&'static str>,
+    }
+    let our = Test {
+        hash: HashSet::from(["123"]),
+        btree: BTreeSet::from(["0123456789", "9876543210"]),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { hash: {"***"}, btree: {"*6789", "*3210"} }"#
+    );
+}
+
+#[test]
+fn mask_all_maps() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all")]
+        hash: HashMap<String, String>,
+        #[deboog(mask = "all")]
+        btree: BTreeMap<&'static str, i32>,
+    }
+    let our = Test {
+        hash: HashMap::from([("key".into(), "value".into())]),
+        btree: BTreeMap::from([("a", 1), ("bb", 22)]),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { hash: {"***": "*****"}, btree: {"*": *, "**": **} }"#
+    );
+}
+
+#[test]
+fn mask_map_keys_and_values() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask_values = "all")]
+        headers: BTreeMap<&'static str, &'static str>,
+        #[deboog(mask_keys = "pan_suffix")]
+        cards: BTreeMap<&'static str, i32>,
+        #[deboog(mask_keys = "all", mask_values = "email")]
+        emails: BTreeMap<&'static str, &'static str>,
+    }
+    let our = Test {
+        headers: BTreeMap::from([("Authorization", "Bearer 123"), ("Host", "example.com")]),
+        cards: BTreeMap::from([("0123456789012345", 1)]),
+        emails: BTreeMap::from([("john", "john@example.com")]),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { headers: {"Authorization": "**********", "Host": "***********"}, cards: {"*2345": 1}, emails: {"****": "j***@example.com"} }"#
+    );
+}
+
+#[test]
+fn mask_map_keys_or_values_of_plain_types() {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    enum Kind {
+        Email,
+        Phone,
+    }
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Id(u32);
+
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask_values = "all")]
+        contacts: BTreeMap<Kind, String>,
+        #[deboog(mask_keys = "pan_suffix")]
+        owners: BTreeMap<String, Id>,
+        #[deboog(mask_values = "all")]
+        hash: HashMap<Kind, &'static str>,
+    }
+    let our = Test {
+        contacts: BTreeMap::from([
+            (Kind::Email, "john@example.com".into()),
+            (Kind::Phone, "+1234".into()),
+        ]),
+        owners: BTreeMap::from([("0123456789".into(), Id(7))]),
+        hash: HashMap::from([(Kind::Email, "secret")]),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { contacts: {Email: "****************", Phone: "*****"}, owners: {"*6789": Id(7)}, hash: {Email: "******"} }"#
+    );
+}
+
+#[test]
+fn mask_generic_map_keys_or_values() {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Id(u32);
+
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test<K, V> {
+        #[deboog(mask_keys = "pan_suffix")]
+        owners: BTreeMap<K, V>,
+        #[deboog(mask_values = "all")]
+        names: BTreeMap<V, K>,
+    }
+    let our = Test::<String, Id> {
+        owners: BTreeMap::from([("0123456789".into(), Id(7))]),
+        names: BTreeMap::from([(Id(8), "john".into())]),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { owners: {"*6789": Id(7)}, names: {Id(8): "****"} }"#
+    );
+}
+
+#[test]
+fn mask_all_tuples() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all")]
+        single: (i32,),
+        #[deboog(mask = "all")]
+        pair: (&'static str, i32),
+        #[deboog(mask = "all")]
+        nested: Vec<(i32, Option<&'static str>)>,
+    }
+    let our = Test {
+        single: (123,),
+        pair: ("test", 45),
+        nested: vec![(1, Some("a")), (22, None)],
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { single: (***,), pair: ("****", **), nested: [(*, Some("*")), (**, None)] }"#
+    );
+}
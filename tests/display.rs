@@ -0,0 +1,226 @@
+use std::fmt::{self, Formatter};
+
+use deboog::{Deboog, DeboogDisplay};
+
+fn fmt_initial(value: &&str, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "{}.", value.chars().next().unwrap_or_default())
+}
+
+#[test]
+fn display_struct() {
+    #[allow(dead_code)]
+    #[derive(Deboog, DeboogDisplay)]
+    #[deboog(display = "{name} <{email}>, card {card}")]
+    struct User {
+        name: &'static str,
+        #[deboog(mask = "email")]
+        email: String,
+        #[deboog(mask = "pan_suffix")]
+        card: String,
+        #[deboog(mask = "all")]
+        password: String,
+    }
+    let user = User {
+        name: "John",
+        email: "john@example.com".into(),
+        card: "1111222233334444".into(),
+        password: "qwerty".into(),
+    };
+    assert_eq!(user.to_string(), "John <j***@example.com>, card *4444");
+    assert_eq!(
+        format!("{:?}", user),
+        r#"User { name: "John", email: "j***@example.com", card: "*4444", password: "******" }"#
+    );
+}
+
+#[test]
+fn display_format_spec() {
+    #[derive(DeboogDisplay)]
+    #[deboog(display = "{{{id:>5}}} {pin:-<6}|{amount:.2}")]
+    struct Test {
+        id: u32,
+        #[deboog(mask = "all")]
+        pin: u16,
+        amount: f64,
+    }
+    let our = Test {
+        id: 42,
+        pin: 1234,
+        amount: 1.5,
+    };
+    assert_eq!(our.to_string(), "{   42} ****--|1.50");
+}
+
+#[test]
+fn display_hidden_with_bytes() {
+    #[derive(DeboogDisplay)]
+    #[deboog(display = "{token}/{secret}/{name}/{key}")]
+    struct Test {
+        #[deboog(mask = "hidden")]
+        token: String,
+        #[deboog(mask = "hidden", placeholder = "<redacted>")]
+        secret: String,
+        #[deboog(with = "fmt_initial")]
+        name: &'static str,
+        #[deboog(bytes = "hex", mask = "pan_suffix")]
+        key: Vec<u8>,
+    }
+    let our = Test {
+        token: "abc".into(),
+        secret: "def".into(),
+        name: "John",
+        key: vec![0xde, 0xad, 0xbe, 0xef],
+    };
+    assert_eq!(our.to_string(), "***/<redacted>/J./*beef");
+}
+
+#[test]
+fn display_bytes() {
+    #[derive(DeboogDisplay)]
+    #[deboog(display = "{key}/{nonce}/{raw}")]
+    struct Test {
+        #[deboog(bytes = "hex", mask = "hidden")]
+        key: Vec<u8>,
+        #[deboog(mask = "hidden", placeholder = "<nonce>")]
+        nonce: Vec<u8>,
+        #[deboog(bytes = "base64")]
+        raw: Vec<u8>,
+    }
+    let our = Test {
+        key: vec![0xde, 0xad, 0xbe, 0xef],
+        nonce: vec![1, 2, 3],
+        raw: vec![0xde, 0xad, 0xbe, 0xef],
+    };
+    assert_eq!(our.to_string(), "***/<nonce>/3q2+7w==");
+}
+
+#[test]
+fn display_tuple_struct() {
+    #[derive(DeboogDisplay)]
+    #[deboog(display = "{0}: {1} ({0})")]
+    struct Test(u8, #[deboog(mask = "pan")] &'static str);
+
+    assert_eq!(
+        Test(1, "1111222233334444").to_string(),
+        "1: 111122******4444 (1)"
+    );
+}
+
+#[test]
+fn display_enum() {
+    #[allow(dead_code)]
+    #[derive(DeboogDisplay)]
+    #[deboog(display = "payment {id}")]
+    enum Event {
+        #[deboog(display = "login failed for {0}")]
+        LoginFailed(#[deboog(mask = "email")] &'static str, u32),
+        #[deboog(display = "card {card} declined")]
+        Declined {
+            #[deboog(mask = "pan_suffix")]
+            card: &'static str,
+            code: u16,
+        },
+        Accepted {
+            id: u32,
+        },
+        #[deboog(display = "logged out")]
+        LoggedOut,
+    }
+    assert_eq!(
+        Event::LoginFailed("john@example.com", 3).to_string(),
+        "login failed for j***@example.com"
+    );
+    assert_eq!(
+        Event::Declined {
+            card: "1111222233334444",
+            code: 51
+        }
+        .to_string(),
+        "card *4444 declined"
+    );
+    assert_eq!(Event::Accepted { id: 7 }.to_string(), "payment 7");
+    assert_eq!(Event::LoggedOut.to_string(), "logged out");
+}
+
+#[test]
+fn display_container_mask() {
+    #[derive(DeboogDisplay)]
+    #[deboog(mask = "all", display = "{login}:{password}")]
+    struct Test {
+        #[deboog(unmask)]
+        login: &'static str,
+        password: &'static str,
+    }
+    assert_eq!(
+        Test {
+            login: "john",
+            password: "qwerty"
+        }
+        .to_string(),
+        "john:******"
+    );
+}
+
+#[test]
+fn display_generic() {
+    #[derive(DeboogDisplay)]
+    #[deboog(display = "{plain} {masked} {hidden}")]
+    struct Test<T, U, V> {
+        plain: T,
+        #[deboog(mask = "pan_suffix")]
+        masked: U,
+        #[deboog(mask = "hidden")]
+        hidden: V,
+    }
+    assert_eq!(
+        Test {
+            plain: 1.5,
+            masked: 123456789,
+            hidden: vec![1u8, 2, 3],
+        }
+        .to_string(),
+        "1.5 *6789 ***"
+    );
+}
+
+#[test]
+fn display_generic_unused() {
+    struct Payload;
+
+    #[derive(DeboogDisplay)]
+    #[deboog(display = "message {id}")]
+    struct Envelope<T> {
+        id: u32,
+        #[allow(dead_code)]
+        payload: T,
+    }
+    let envelope = Envelope {
+        id: 7,
+        payload: Payload,
+    };
+    assert_eq!(envelope.to_string(), "message 7");
+}
+
+#[cfg(any(debug_assertions, feature = "reveal-in-release"))]
+#[test]
+fn display_reveal() {
+    use deboog::policy::{self, Policy};
+
+    #[derive(DeboogDisplay)]
+    #[deboog(display = "{card} {token} {key}")]
+    struct Test {
+        #[deboog(mask = "pan")]
+        card: &'static str,
+        #[deboog(mask = "hidden")]
+        token: &'static str,
+        #[deboog(bytes = "hex", mask = "hidden")]
+        key: Vec<u8>,
+    }
+    let our = Test {
+        card: "1111222233334444",
+        token: "abc",
+        key: vec![0xde, 0xad, 0xbe, 0xef],
+    };
+    let _guard = policy::scoped(Policy::Reveal);
+    assert_eq!(our.to_string(), r#"1111222233334444 "abc" deadbeef"#);
+}
@@ -1,4 +1,4 @@
-use deboog::Deboog;
+use deboog::{masking::mask_pan, Deboog};
 
 #[test]
 fn eq_unit_struct() {
@@ -41,11 +41,13 @@ fn eq_normal_struct() {
 #[test]
 fn eq_tuple_struct() {
     let standard = {
+        #[allow(dead_code)]
         #[derive(Debug)]
         struct Tuple(i32, &'static str);
         Tuple(123, "test")
     };
     let our = {
+        #[allow(dead_code)]
         #[derive(Deboog)]
         struct Tuple(i32, &'static str);
         Tuple(123, "test")
@@ -115,6 +117,7 @@ fn eq_enum_w_fields() {
 #[test]
 fn eq_enum_w_tuple() {
     let standard = {
+        #[allow(dead_code)]
         #[derive(Debug)]
         enum With {
             Tuple(i32, &'static str),
@@ -122,6 +125,7 @@ fn eq_enum_w_tuple() {
         With::Tuple(123, "test")
     };
     let our = {
+        #[allow(dead_code)]
         #[derive(Deboog)]
         enum With {
             Tuple(i32, &'static str),
@@ -130,3 +134,171 @@ fn eq_enum_w_tuple() {
     };
     assert_eq!(format!("{:?}", standard), format!("{:?}", our));
 }
+
+#[test]
+fn eq_pretty_normal_struct() {
+    let standard = {
+        #[allow(dead_code)]
+        #[derive(Debug)]
+        struct Normal {
+            a: i32,
+            b: &'static str,
+        }
+        Normal { a: 123, b: "test" }
+    };
+    let our = {
+        #[allow(dead_code)]
+        #[derive(Deboog)]
+        struct Normal {
+            a: i32,
+            #[deboog(mask = "pan")]
+            b: &'static str,
+        }
+        Normal { a: 123, b: "test" }
+    };
+    assert_eq!(format!("{:#?}", standard), format!("{:#?}", our));
+}
+
+#[test]
+fn eq_pretty_masked_collections() {
+    let standard = {
+        #[allow(dead_code)]
+        #[derive(Debug)]
+        struct Collections {
+            a: Vec<&'static str>,
+            b: Option<Vec<i32>>,
+            c: Vec<Option<&'static str>>,
+            d: Option<&'static str>,
+            e: Vec<i32>,
+        }
+        Collections {
+            a: vec!["test", "more"],
+            b: Some(vec![1, 2]),
+            c: vec![Some("test"), None],
+            d: None,
+            e: vec![],
+        }
+    };
+    let our = {
+        #[allow(dead_code)]
+        #[derive(Deboog)]
+        #[deboog(mask = "pan")]
+        struct Collections {
+            a: Vec<&'static str>,
+            b: Option<Vec<i32>>,
+            c: Vec<Option<&'static str>>,
+            d: Option<&'static str>,
+            e: Vec<i32>,
+        }
+        Collections {
+            a: vec!["test", "more"],
+            b: Some(vec![1, 2]),
+            c: vec![Some("test"), None],
+            d: None,
+            e: vec![],
+        }
+    };
+    assert_eq!(format!("{:#?}", standard), format!("{:#?}", our));
+}
+
+#[test]
+fn eq_pretty_masked_nested() {
+    let standard = {
+        #[allow(dead_code)]
+        #[derive(Debug)]
+        enum Nested {
+            Tuple(Option<(i32, &'static str)>, [u8; 2]),
+            Fields { a: Result<Vec<i32>, i32> },
+        }
+        vec![
+            Nested::Tuple(Some((1, "test")), [1, 2]),
+            Nested::Fields { a: Ok(vec![3]) },
+        ]
+    };
+    let our = {
+        #[allow(dead_code)]
+        #[derive(Deboog)]
+        enum Nested {
+            Tuple(
+                #[deboog(mask = "pan")] Option<(i32, &'static str)>,
+                #[deboog(mask = "pan")] [u8; 2],
+            ),
+            Fields {
+                #[deboog(mask = "pan")]
+                a: Result<Vec<i32>, i32>,
+            },
+        }
+        vec![
+            Nested::Tuple(Some((1, "test")), [1, 2]),
+            Nested::Fields { a: Ok(vec![3]) },
+        ]
+    };
+    assert_eq!(format!("{:#?}", standard), format!("{:#?}", our));
+}
+
+/// Pre-formatted value, printed as is regardless of formatter options
+struct Raw(String);
+
+impl std::fmt::Debug for Raw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Masks formatted value with `pan` strategy, leaving fill characters around it intact
+fn pan(formatted: String, fill: char) -> Raw {
+    let value = formatted.trim_matches(fill);
+    let start = formatted.find(value).unwrap_or(0);
+    Raw(format!(
+        "{}{}{}",
+        &formatted[..start],
+        mask_pan(value),
+        &formatted[start + value.len()..]
+    ))
+}
+
+#[test]
+fn eq_format_options() {
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Numbers<T> {
+        a: T,
+        b: T,
+        c: Option<T>,
+        d: T,
+    }
+    let our = {
+        #[allow(dead_code)]
+        #[derive(Deboog)]
+        #[deboog(mask = "pan")]
+        struct Numbers {
+            a: i64,
+            b: f64,
+            c: Option<u64>,
+            d: bool,
+        }
+        Numbers {
+            a: -1234567890123,
+            b: 12345.678901,
+            c: Some(4111111111111111),
+            d: true,
+        }
+    };
+    macro_rules! check {
+        ($spec:literal, $fill:literal) => {
+            let standard = Numbers {
+                a: pan(format!($spec, our.a), $fill),
+                b: pan(format!($spec, our.b), $fill),
+                c: our.c.map(|c| pan(format!($spec, c), $fill)),
+                d: pan(format!($spec, our.d), $fill),
+            };
+            assert_eq!(format!($spec, standard), format!($spec, our));
+        };
+    }
+    check!("{:24?}", ' ');
+    check!("{:<24?}", ' ');
+    check!("{:_^25?}", '_');
+    check!("{:024.2?}", ' ');
+    check!("{:+?}", ' ');
+    check!("{:+#024?}", ' ');
+}
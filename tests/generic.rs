@@ -0,0 +1,150 @@
+use deboog::{Deboog, DeboogField, MaskType};
+
+#[test]
+fn generic_struct() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Envelope<T> {
+        id: i32,
+        payload: T,
+    }
+    let our = Envelope {
+        id: 123,
+        payload: "test",
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Envelope { id: 123, payload: "test" }"#
+    );
+}
+
+#[test]
+fn generic_lifetime_struct() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Page<'a, T> {
+        items: &'a [T],
+        #[deboog(mask = "all")]
+        token: &'a str,
+    }
+    let items = [1, 2, 3];
+    let our = Page {
+        items: &items,
+        token: "secret",
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Page { items: [1, 2, 3], token: "******" }"#
+    );
+}
+
+#[test]
+fn generic_const_struct() {
+    #[derive(Deboog)]
+    struct Fixed<const N: usize>([i32; N]);
+    let our = Fixed([1, 2]);
+    assert_eq!(format!("{:?}", our), r#"Fixed([1, 2])"#);
+}
+
+#[test]
+fn generic_masked_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Envelope<T> {
+        #[deboog(mask = "pan")]
+        payload: Option<T>,
+    }
+    let our = Envelope {
+        payload: Some("0123456789012345"),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Envelope { payload: Some("012345******2345") }"#
+    );
+}
+
+#[test]
+fn generic_where_clause() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Wrapper<T>
+    where
+        T: Clone,
+    {
+        value: T,
+    }
+    let our = Wrapper { value: 123 };
+    assert_eq!(format!("{:?}", our), r#"Wrapper { value: 123 }"#);
+}
+
+#[test]
+fn generic_enum() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    enum Reply<T, E> {
+        Ok(T),
+        Err {
+            #[deboog(mask = "all")]
+            error: E,
+        },
+    }
+    let our: Reply<i32, &str> = Reply::Ok(123);
+    assert_eq!(format!("{:?}", our), r#"Ok(123)"#);
+}
+
+#[test]
+fn generic_manual_bound() {
+    trait Backend {
+        type Key: std::fmt::Debug;
+    }
+    struct Local;
+    impl Backend for Local {
+        type Key = u32;
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(bound = "B::Key: std::fmt::Debug")]
+    struct Handle<B: Backend> {
+        key: B::Key,
+    }
+    let our: Handle<Local> = Handle { key: 123 };
+    assert_eq!(format!("{:?}", our), r#"Handle { key: 123 }"#);
+}
+
+#[test]
+fn generic_custom_field_type() {
+    struct Secret;
+
+    impl DeboogField for Secret {
+        fn fmt_masked(
+            &self,
+            f: &mut std::fmt::Formatter<'_>,
+            _mask_type: MaskType,
+        ) -> std::fmt::Result {
+            write!(f, "SECRET")
+        }
+    }
+
+    impl std::fmt::Debug for Secret {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Secret")
+        }
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Holder<T> {
+        plain: T,
+        #[deboog(mask = "all")]
+        masked: T,
+    }
+    let our = Holder {
+        plain: Secret,
+        masked: Secret,
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Holder { plain: Secret, masked: SECRET }"#
+    );
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "hash")]
+
+use deboog::Deboog;
+
+#[test]
+fn mask_hash() {
+    use deboog::masking;
+
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "hash")]
+        token: String,
+        #[deboog(mask = "hash")]
+        card: Option<&'static str>,
+        #[deboog(mask = "hash")]
+        number: i32,
+    }
+    let our = Test {
+        token: "secret".into(),
+        card: Some("4111111111111111"),
+        number: 42,
+    };
+
+    // Key registry is global, so all checks live in a single test.
+    masking::clear_hash_key();
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { token: "******", card: Some("****************"), number: ** }"#
+    );
+
+    masking::set_hash_key("key");
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { token: "sha256:25cf3c44c8f3…", card: Some("sha256:d7deb2f89396…"), number: sha256:f2991b7ce981… }"#
+    );
+    masking::clear_hash_key();
+}
@@ -158,3 +158,429 @@ fn mask_hidden_struct_field() {
         r#"Test { a: "0123456789012345", b: *** }"#
     );
 }
+
+#[test]
+fn mask_pan_variant_struct_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    enum PaymentMethod {
+        Cash,
+        Card {
+            holder: &'static str,
+            #[deboog(mask = "pan")]
+            pan: &'static str,
+            #[deboog(mask = "hidden")]
+            cvv: &'static str,
+        },
+    }
+    let our = PaymentMethod::Cash;
+    assert_eq!(format!("{:?}", our), "Cash");
+    let our = PaymentMethod::Card {
+        holder: "John Doe",
+        pan: "0123456789012345",
+        cvv: "123",
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Card { holder: "John Doe", pan: "012345******2345", cvv: *** }"#
+    );
+}
+
+#[test]
+fn mask_pan_variant_tuple_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    enum PaymentMethod {
+        Cash,
+        Card(
+            &'static str,
+            #[deboog(mask = "pan")] &'static str,
+            #[deboog(skip)] &'static str,
+            #[deboog(mask = "all")] &'static str,
+        ),
+    }
+    let our = PaymentMethod::Cash;
+    assert_eq!(format!("{:?}", our), "Cash");
+    let our = PaymentMethod::Card("John Doe", "0123456789012345", "skipped", "123");
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Card("John Doe", "012345******2345", "***")"#
+    );
+}
+
+#[test]
+fn mask_email_struct_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "email")]
+        a: &'static str,
+        #[deboog(mask(email(keep_domain = false)))]
+        b: String,
+        #[deboog(mask = "email")]
+        c: &'static str,
+    }
+    let our = Test {
+        a: "john@example.com",
+        b: "john@example.com".into(),
+        c: "not an email",
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { a: "j***@example.com", b: "j***@*******.com", c: "************" }"#
+    );
+}
+
+#[test]
+fn mask_email_fallback_struct_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask(email(fallback = "preserve_format")))]
+        a: &'static str,
+        #[deboog(mask(email(keep_domain = false, fallback = "length")))]
+        b: String,
+    }
+    let our = Test {
+        a: "not an email",
+        b: "john@localhost".into(),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { a: "xxx xx xxxxx", b: "<redacted 14 chars>" }"#
+    );
+    let our = Test {
+        a: "john@example.com",
+        b: "john@example.com".into(),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { a: "j***@example.com", b: "j***@*******.com" }"#
+    );
+}
+
+#[test]
+fn mask_list_form_struct_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask(pan))]
+        a: &'static str,
+        #[deboog(mask(email()))]
+        b: &'static str,
+    }
+    let our = Test {
+        a: "0123456789012345",
+        b: "john@example.com",
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { a: "012345******2345", b: "j***@example.com" }"#
+    );
+}
+
+#[test]
+fn mask_partial_struct_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask(keep_start = 2, keep_end = 3, char = '#'))]
+        account: &'static str,
+        #[deboog(mask(keep_end = 4))]
+        national_id: String,
+        #[deboog(mask(keep_start = 2, keep_end = 2, char = '-'))]
+        number: u64,
+    }
+    let our = Test {
+        account: "40817810099910004312",
+        national_id: "4510123456".into(),
+        number: 79161234567,
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { account: "40###############312", national_id: "******3456", number: 79-------67 }"#
+    );
+}
+
+#[test]
+fn mask_partial_variant_tuple_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    enum Test {
+        Phone(#[deboog(mask(keep_start = 2, keep_end = 2))] &'static str),
+    }
+    let our = Test::Phone("+79161234567");
+    assert_eq!(format!("{:?}", our), r#"Phone("+7********67")"#);
+}
+
+#[test]
+fn mask_hidden_placeholder_struct_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "hidden", placeholder = "<redacted>")]
+        a: &'static str,
+        #[deboog(mask = "hidden")]
+        b: &'static str,
+    }
+    let our = Test {
+        a: "0123456789012345",
+        b: "0123456789012345",
+    };
+    assert_eq!(format!("{:?}", our), r#"Test { a: <redacted>, b: *** }"#);
+}
+
+#[test]
+fn mask_hidden_container_placeholder() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(placeholder = "[REDACTED]")]
+    enum Test {
+        Tuple(#[deboog(mask = "hidden")] i32),
+        Struct {
+            #[deboog(mask = "hidden")]
+            a: &'static str,
+            #[deboog(mask = "hidden", placeholder = "<redacted>")]
+            b: &'static str,
+            #[deboog(mask = "all")]
+            c: &'static str,
+        },
+    }
+    let our = Test::Tuple(123);
+    assert_eq!(format!("{:?}", our), r#"Tuple([REDACTED])"#);
+    let our = Test::Struct {
+        a: "012345",
+        b: "012345",
+        c: "012345",
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Struct { a: [REDACTED], b: <redacted>, c: "******" }"#
+    );
+}
+
+#[test]
+fn mask_container_default() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(mask = "all")]
+    struct Test {
+        a: &'static str,
+        #[deboog(mask = "pan_suffix")]
+        b: &'static str,
+        #[deboog(unmask)]
+        c: &'static str,
+        #[deboog(skip)]
+        d: &'static str,
+    }
+    let our = Test {
+        a: "0123456789",
+        b: "0123456789",
+        c: "0123456789",
+        d: "0123456789",
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { a: "**********", b: "*6789", c: "0123456789" }"#
+    );
+}
+
+#[test]
+fn mask_container_default_hidden_enum() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(mask = "hidden", placeholder = "<redacted>")]
+    enum Test {
+        Tuple(i32, #[deboog(unmask)] i32),
+        Struct {
+            a: &'static str,
+            #[deboog(unmask)]
+            b: &'static str,
+        },
+    }
+    let our = Test::Tuple(123, 234);
+    assert_eq!(format!("{:?}", our), "Tuple(<redacted>, 234)");
+    let our = Test::Struct { a: "012", b: "345" };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Struct { a: <redacted>, b: "345" }"#
+    );
+}
+
+#[test]
+fn mask_container_default_generic() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(mask = "all")]
+    struct Test<T> {
+        a: T,
+        #[deboog(unmask)]
+        b: i32,
+    }
+    let our = Test { a: "012", b: 345 };
+    assert_eq!(format!("{:?}", our), r#"Test { a: "***", b: 345 }"#);
+}
+
+#[test]
+fn mask_length() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "length")]
+        a: &'static str,
+        #[deboog(mask = "length")]
+        b: Option<String>,
+        #[deboog(mask = "length")]
+        c: i32,
+    }
+    let our = Test {
+        a: "0123456789012345",
+        b: Some(String::new()),
+        c: 7,
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { a: "<redacted 16 chars>", b: Some("<redacted 0 chars>"), c: <redacted 1 char> }"#
+    );
+}
+
+#[test]
+fn mask_length_bucket() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "length_bucket")]
+        a: &'static str,
+        #[deboog(mask = "length_bucket")]
+        b: &'static str,
+        #[deboog(mask = "length_bucket")]
+        c: u64,
+    }
+    let our = Test {
+        a: "0123456789012345",
+        b: "",
+        c: 1234567,
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { a: "<redacted ~10-20 chars>", b: "<redacted 0 chars>", c: <redacted ~5-10 chars> }"#
+    );
+}
+
+#[test]
+fn mask_card() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "card")]
+        a: &'static str,
+        #[deboog(mask(card(brand = true)))]
+        b: String,
+        #[deboog(mask(card(brand = false)))]
+        c: Option<&'static str>,
+        #[deboog(mask = "card")]
+        d: &'static str,
+        #[deboog(mask = "card")]
+        e: u64,
+    }
+    let our = Test {
+        a: "4111-1111-1111-1111",
+        b: "378282246310005".into(),
+        c: Some("5555555555554444"),
+        d: "4111111111111112",
+        e: 6011111111111117,
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { a: "4111-11**-****-1111", b: "AMEX ****0005", c: Some("555555******4444"), d: "****************", e: 601111******1117 }"#
+    );
+}
+
+#[test]
+fn mask_phone() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "phone")]
+        a: &'static str,
+        #[deboog(mask = "phone")]
+        b: Option<String>,
+        #[deboog(mask = "phone")]
+        c: &'static str,
+    }
+    let our = Test {
+        a: "+7 (912) 345-67-67",
+        b: Some("+44 20 7946 0958".into()),
+        c: "12-34",
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { a: "+7 (***) ***-**-67", b: Some("+44 ** **** 0958"), c: "*****" }"#
+    );
+}
+
+#[test]
+fn mask_preserve_format() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "preserve_format")]
+        a: &'static str,
+        #[deboog(mask = "preserve_format")]
+        b: Vec<String>,
+        #[deboog(mask = "preserve_format")]
+        c: f64,
+    }
+    let our = Test {
+        a: "GB82 WEST 1234",
+        b: vec!["f47ac10b-58cc".into()],
+        c: -1.5,
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { a: "XX## XXXX ####", b: ["x##xx##x-##xx"], c: -#.# }"#
+    );
+}
+
+#[test]
+fn mask_format_options() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all")]
+        a: i32,
+        #[deboog(mask = "all")]
+        b: f64,
+    }
+    let our = Test { a: -12, b: 1.5 };
+    assert_eq!(format!("{:6?}", our), r#"Test { a:    ***, b:    *** }"#);
+    assert_eq!(format!("{:<6?}", our), r#"Test { a: ***   , b: ***    }"#);
+    assert_eq!(
+        format!("{:08.2?}", our),
+        r#"Test { a: ********, b: ******** }"#
+    );
+    assert_eq!(format!("{:+?}", our), r#"Test { a: ***, b: **** }"#);
+}
+
+#[test]
+fn mask_format_hex_options() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all")]
+        a: u32,
+        #[deboog(mask = "pan_suffix")]
+        b: i64,
+    }
+    // Hexadecimal debug flags are not exposed by Formatter, so values are masked in decimal form
+    let our = Test {
+        a: 255,
+        b: 0xabcdef,
+    };
+    assert_eq!(format!("{:x?}", our), r#"Test { a: ***, b: *9375 }"#);
+    assert_eq!(format!("{:X?}", our), r#"Test { a: ***, b: *9375 }"#);
+    assert_eq!(
+        format!("{:#010x?}", Test { a: 255, b: 0xff }),
+        "Test {\n    a: **********,\n    b:      *0255,\n}"
+    );
+}
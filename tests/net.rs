@@ -0,0 +1,66 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use deboog::Deboog;
+
+#[test]
+fn mask_ip_subnet() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "ip_subnet")]
+        v4: Ipv4Addr,
+        #[deboog(mask = "ip_subnet")]
+        v6: Ipv6Addr,
+        #[deboog(mask = "ip_subnet")]
+        ip: IpAddr,
+        #[deboog(mask = "ip_subnet")]
+        socket: SocketAddr,
+        #[deboog(mask = "ip_subnet")]
+        string: String,
+    }
+    let our = Test {
+        v4: Ipv4Addr::new(192, 168, 1, 23),
+        v6: "2001:db8:85a3::8a2e:370:7334".parse().unwrap(),
+        ip: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+        socket: "[2001:db8::1]:443".parse().unwrap(),
+        string: "192.168.1.23:8080".into(),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { v4: 192.168.1.x, v6: 2001:db8:85a3:x:x:x:x:x, ip: 10.0.0.x, socket: [2001:db8:0:x:x:x:x:x]:443, string: "192.168.1.x:8080" }"#
+    );
+}
+
+#[test]
+fn mask_ip_port() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "ip_port")]
+        socket: SocketAddr,
+        #[deboog(mask = "ip_port")]
+        ip: Option<IpAddr>,
+    }
+    let our = Test {
+        socket: "192.168.1.23:8080".parse().unwrap(),
+        ip: Some("::1".parse().unwrap()),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { socket: x.x.x.x:8080, ip: Some(x:x:x:x:x:x:x:x) }"#
+    );
+}
+
+#[test]
+fn mask_all_ip() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all")]
+        ip: Ipv4Addr,
+    }
+    let our = Test {
+        ip: Ipv4Addr::new(10, 0, 0, 1),
+    };
+    assert_eq!(format!("{:?}", our), r#"Test { ip: ******** }"#);
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use deboog::{
+    policy::{self, Policy},
+    Deboog, DeboogField,
+};
+
+#[allow(dead_code)]
+#[derive(Deboog)]
+struct Test {
+    #[deboog(mask = "all")]
+    a: &'static str,
+    #[deboog(mask = "pan")]
+    b: Option<String>,
+    #[deboog(mask = "hidden")]
+    c: i32,
+    #[deboog(mask = "hidden", placeholder = "<redacted>")]
+    d: f64,
+    #[deboog(mask_values = "all")]
+    e: HashMap<&'static str, &'static str>,
+    #[deboog(bytes = "hex", mask = "pan_suffix")]
+    f: Vec<u8>,
+}
+
+fn test_value() -> Test {
+    Test {
+        a: "secret",
+        b: Some("0123456789012345".into()),
+        c: 123,
+        d: 1.0,
+        e: HashMap::from([("key", "value")]),
+        f: vec![0xde, 0xad, 0xbe, 0xef],
+    }
+}
+
+#[cfg(any(debug_assertions, feature = "reveal-in-release"))]
+#[test]
+fn policy_scoped_reveal() {
+    let our = test_value();
+    {
+        let _guard = policy::scoped(Policy::Reveal);
+        assert_eq!(policy::current(), Policy::Reveal);
+        assert_eq!(
+            format!("{:?}", our),
+            r#"Test { a: "secret", b: Some("0123456789012345"), c: 123, d: 1.0, e: {"key": "value"}, f: deadbeef }"#
+        );
+    }
+    let _guard = policy::scoped(Policy::Mask);
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { a: "******", b: Some("012345******2345"), c: ***, d: <redacted>, e: {"key": "*****"}, f: *beef }"#
+    );
+}
+
+#[cfg(any(debug_assertions, feature = "reveal-in-release"))]
+#[test]
+fn policy_scoped_nested() {
+    let _outer = policy::scoped(Policy::Reveal);
+    {
+        let _inner = policy::scoped(Policy::Mask);
+        assert!(!policy::reveals());
+    }
+    assert!(policy::reveals());
+}
+
+#[cfg(any(debug_assertions, feature = "reveal-in-release"))]
+#[test]
+fn policy_scoped_thread_local() {
+    let _guard = policy::scoped(Policy::Reveal);
+    std::thread::spawn(|| {
+        let _guard = policy::scoped(Policy::Mask);
+        assert!(!policy::reveals());
+    })
+    .join()
+    .unwrap();
+    assert!(policy::reveals());
+}
+
+#[cfg(any(debug_assertions, feature = "reveal-in-release"))]
+#[test]
+fn policy_global() {
+    policy::set(Policy::Reveal);
+    std::thread::spawn(|| assert!(policy::reveals()))
+        .join()
+        .unwrap();
+    {
+        let _guard = policy::scoped(Policy::Mask);
+        assert!(!policy::reveals());
+    }
+    policy::set(Policy::Mask);
+    std::thread::spawn(|| assert!(!policy::reveals()))
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn policy_reveal_default_impl() {
+    struct Item;
+
+    impl DeboogField for Item {}
+
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "hidden")]
+        item: Item,
+    }
+    let _guard = policy::scoped(Policy::Reveal);
+    assert_eq!(
+        format!("{:?}", Test { item: Item }),
+        r#"Test { item: *** }"#
+    );
+}
+
+#[cfg(not(any(debug_assertions, feature = "reveal-in-release")))]
+#[test]
+fn policy_reveal_not_allowed() {
+    policy::set(Policy::Reveal);
+    let _guard = policy::scoped(Policy::Reveal);
+    assert!(!policy::reveals());
+    assert_eq!(
+        format!("{:?}", test_value()),
+        r#"Test { a: "******", b: Some("012345******2345"), c: ***, d: <redacted>, e: {"key": "*****"}, f: *beef }"#
+    );
+}
@@ -0,0 +1,124 @@
+use deboog::Deboog;
+
+#[test]
+fn rename_struct() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(rename = "User")]
+    struct UserRecord {
+        id: i32,
+    }
+    let our = UserRecord { id: 123 };
+    assert_eq!(format!("{:?}", our), "User { id: 123 }");
+}
+
+#[test]
+fn rename_tuple_struct() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(rename = "Id")]
+    struct UserId(i32);
+    let our = UserId(123);
+    assert_eq!(format!("{:?}", our), "Id(123)");
+}
+
+#[test]
+fn rename_struct_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(rename = "identifier")]
+        id: i32,
+        user_name: &'static str,
+    }
+    let our = Test {
+        id: 123,
+        user_name: "test",
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { identifier: 123, user_name: "test" }"#
+    );
+}
+
+#[test]
+fn rename_all_struct_fields() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(rename_all = "camelCase")]
+    struct Test {
+        user_id: i32,
+        #[deboog(rename = "name")]
+        user_name: &'static str,
+        r#type: i32,
+    }
+    let our = Test {
+        user_id: 123,
+        user_name: "test",
+        r#type: 1,
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { userId: 123, name: "test", type: 1 }"#
+    );
+}
+
+#[test]
+fn rename_all_rules() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(rename_all = "SCREAMING-KEBAB-CASE")]
+    struct Kebab {
+        user_id: i32,
+    }
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(rename_all = "PascalCase")]
+    struct Pascal {
+        user_id: i32,
+    }
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(rename_all = "UPPERCASE")]
+    struct Upper {
+        user_id: i32,
+    }
+    assert_eq!(
+        format!("{:?}", Kebab { user_id: 1 }),
+        "Kebab { USER-ID: 1 }"
+    );
+    assert_eq!(
+        format!("{:?}", Pascal { user_id: 1 }),
+        "Pascal { UserId: 1 }"
+    );
+    assert_eq!(
+        format!("{:?}", Upper { user_id: 1 }),
+        "Upper { USER_ID: 1 }"
+    );
+}
+
+#[test]
+fn rename_all_enum_variants() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(rename_all = "snake_case")]
+    enum Test {
+        EmptyValue,
+        TupleVariant(i32),
+        #[deboog(rename = "renamed", rename_all = "kebab-case")]
+        StructVariant {
+            field_name: i32,
+            #[deboog(rename = "Other")]
+            other_name: i32,
+        },
+    }
+    let our = Test::EmptyValue;
+    assert_eq!(format!("{:?}", our), "empty_value");
+    let our = Test::TupleVariant(123);
+    assert_eq!(format!("{:?}", our), "tuple_variant(123)");
+    let our = Test::StructVariant {
+        field_name: 1,
+        other_name: 2,
+    };
+    assert_eq!(format!("{:?}", our), "renamed { field-name: 1, Other: 2 }");
+}
@@ -0,0 +1,307 @@
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use deboog::{Deboog, DeboogSerialize};
+use serde_json::json;
+
+#[test]
+fn serialize_struct() {
+    #[allow(dead_code)]
+    #[derive(Deboog, DeboogSerialize)]
+    struct Test {
+        id: i32,
+        #[deboog(mask = "pan")]
+        card: String,
+        #[deboog(mask = "all")]
+        pin: u16,
+        #[deboog(skip)]
+        password: String,
+        #[deboog(mask = "hidden")]
+        token: String,
+        #[deboog(mask = "hidden", placeholder = "<redacted>")]
+        secret: String,
+        #[deboog(rename = "mail", mask = "email")]
+        email: Option<&'static str>,
+    }
+    let our = Test {
+        id: 123,
+        card: "0123456789012345".into(),
+        pin: 1234,
+        password: "qwerty".into(),
+        token: "abc".into(),
+        secret: "def".into(),
+        email: Some("john@example.com"),
+    };
+    assert_eq!(
+        serde_json::to_value(&our).unwrap(),
+        json!({
+            "id": 123,
+            "card": "012345******2345",
+            "pin": "****",
+            "token": "***",
+            "secret": "<redacted>",
+            "mail": "j***@example.com",
+        })
+    );
+}
+
+#[test]
+fn serialize_collections() {
+    #[allow(dead_code)]
+    #[derive(DeboogSerialize)]
+    struct Test {
+        #[deboog(mask = "all")]
+        list: Vec<Option<&'static str>>,
+        #[deboog(mask = "pan_suffix")]
+        tuple: (i32, String),
+        #[deboog(mask_values = "all")]
+        map: BTreeMap<&'static str, &'static str>,
+        #[deboog(mask = "all")]
+        masked_map: BTreeMap<&'static str, u8>,
+        #[deboog(bytes = "hex", mask = "pan_suffix")]
+        bytes: Vec<u8>,
+    }
+    let our = Test {
+        list: vec![Some("abc"), None],
+        tuple: (123456, "0123456789".into()),
+        map: BTreeMap::from([("key", "value")]),
+        masked_map: BTreeMap::from([("key", 5)]),
+        bytes: vec![0xde, 0xad, 0xbe, 0xef],
+    };
+    assert_eq!(
+        serde_json::to_value(&our).unwrap(),
+        json!({
+            "list": ["***", null],
+            "tuple": ["*3456", "*6789"],
+            "map": {"key": "*****"},
+            "masked_map": {"***": "*"},
+            "bytes": "*beef",
+        })
+    );
+}
+
+#[test]
+fn serialize_map_keys_or_values_of_plain_types() {
+    use std::time::Duration;
+
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    struct Kind(&'static str);
+
+    impl serde::Serialize for Kind {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.0)
+        }
+    }
+
+    #[derive(DeboogSerialize)]
+    struct Test {
+        #[deboog(mask_values = "all")]
+        contacts: BTreeMap<Kind, String>,
+        #[deboog(mask_keys = "pan_suffix")]
+        timeouts: BTreeMap<String, Duration>,
+    }
+    let our = Test {
+        contacts: BTreeMap::from([(Kind("email"), "john@example.com".into())]),
+        timeouts: BTreeMap::from([("0123456789".into(), Duration::from_secs(1))]),
+    };
+    assert_eq!(
+        serde_json::to_value(&our).unwrap(),
+        json!({
+            "contacts": {"email": "****************"},
+            "timeouts": {"*6789": {"secs": 1, "nanos": 0}},
+        })
+    );
+}
+
+#[test]
+fn serialize_generic_map_keys_or_values() {
+    #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    struct Kind(&'static str);
+
+    impl serde::Serialize for Kind {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.0)
+        }
+    }
+
+    #[derive(DeboogSerialize)]
+    struct Test<K, V> {
+        #[deboog(mask_keys = "pan_suffix")]
+        owners: BTreeMap<K, V>,
+        #[deboog(mask_values = "all")]
+        contacts: BTreeMap<V, K>,
+    }
+    let our = Test::<String, Kind> {
+        owners: BTreeMap::from([("0123456789".into(), Kind("admin"))]),
+        contacts: BTreeMap::from([(Kind("email"), "john@example.com".into())]),
+    };
+    assert_eq!(
+        serde_json::to_value(&our).unwrap(),
+        json!({
+            "owners": {"*6789": "admin"},
+            "contacts": {"email": "****************"},
+        })
+    );
+}
+
+#[test]
+fn serialize_skip_if_and_with() {
+    fn fmt_secret(_: &&str, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<secret>")
+    }
+
+    #[allow(dead_code)]
+    #[derive(DeboogSerialize)]
+    #[deboog(rename_all = "camelCase")]
+    struct Test {
+        #[deboog(skip_if = "Option::is_none", mask = "all")]
+        first_name: Option<&'static str>,
+        #[deboog(skip_if = "Option::is_none")]
+        last_name: Option<&'static str>,
+        #[deboog(with = "fmt_secret")]
+        api_key: &'static str,
+    }
+    let our = Test {
+        first_name: Some("John"),
+        last_name: None,
+        api_key: "0123",
+    };
+    assert_eq!(
+        serde_json::to_value(&our).unwrap(),
+        json!({"firstName": "****", "apiKey": "<secret>"})
+    );
+}
+
+#[test]
+fn serialize_tuple_and_unit_structs() {
+    #[derive(DeboogSerialize)]
+    struct Unit;
+
+    #[derive(DeboogSerialize)]
+    struct Newtype(#[deboog(mask = "all")] &'static str);
+
+    #[allow(dead_code)]
+    #[derive(DeboogSerialize)]
+    struct Tuple(i32, #[deboog(skip)] i32, #[deboog(mask = "all")] i32);
+
+    assert_eq!(serde_json::to_value(Unit).unwrap(), json!(null));
+    assert_eq!(
+        serde_json::to_value(Newtype("secret")).unwrap(),
+        json!("******")
+    );
+    assert_eq!(
+        serde_json::to_value(Tuple(1, 2, 345)).unwrap(),
+        json!([1, "***"])
+    );
+}
+
+#[test]
+fn serialize_enum() {
+    #[allow(dead_code)]
+    #[derive(DeboogSerialize)]
+    #[deboog(rename_all = "snake_case")]
+    enum Test {
+        Unit,
+        Newtype(#[deboog(mask = "all")] String),
+        Tuple(i32, #[deboog(mask = "hidden")] i32),
+        #[deboog(rename_all = "UPPERCASE")]
+        Struct {
+            id: i32,
+            #[deboog(mask = "pan")]
+            card: &'static str,
+        },
+    }
+    assert_eq!(serde_json::to_value(Test::Unit).unwrap(), json!("unit"));
+    assert_eq!(
+        serde_json::to_value(Test::Newtype("abc".into())).unwrap(),
+        json!({"newtype": "***"})
+    );
+    assert_eq!(
+        serde_json::to_value(Test::Tuple(1, 2)).unwrap(),
+        json!({"tuple": [1, "***"]})
+    );
+    assert_eq!(
+        serde_json::to_value(Test::Struct {
+            id: 1,
+            card: "0123456789012345"
+        })
+        .unwrap(),
+        json!({"struct": {"ID": 1, "CARD": "012345******2345"}})
+    );
+}
+
+#[test]
+fn serialize_locked() {
+    use std::sync::{Mutex, RwLock};
+
+    #[derive(DeboogSerialize)]
+    struct Test {
+        #[deboog(mask = "all")]
+        mutex: Mutex<String>,
+        #[deboog(mask = "all")]
+        rw_lock: RwLock<String>,
+    }
+    let our = Test {
+        mutex: Mutex::new("abc".into()),
+        rw_lock: RwLock::new("def".into()),
+    };
+    assert_eq!(
+        serde_json::to_value(&our).unwrap(),
+        json!({"mutex": "***", "rw_lock": "***"})
+    );
+    {
+        let _guard = our.mutex.lock().unwrap();
+        let err = serde_json::to_value(&our).unwrap_err();
+        assert_eq!(err.to_string(), "already locked");
+    }
+    let _guard = our.rw_lock.write().unwrap();
+    assert!(serde_json::to_value(&our).is_err());
+}
+
+#[test]
+fn serialize_generic() {
+    #[allow(dead_code)]
+    #[derive(DeboogSerialize)]
+    #[deboog(mask = "all")]
+    struct Test<T, U> {
+        value: T,
+        #[deboog(unmask)]
+        plain: U,
+    }
+    let our = Test {
+        value: vec!["abc"],
+        plain: vec!["def"],
+    };
+    assert_eq!(
+        serde_json::to_value(&our).unwrap(),
+        json!({"value": ["***"], "plain": ["def"]})
+    );
+}
+
+#[cfg(any(debug_assertions, feature = "reveal-in-release"))]
+#[test]
+fn serialize_reveal() {
+    use deboog::policy::{self, Policy};
+
+    #[allow(dead_code)]
+    #[derive(DeboogSerialize)]
+    struct Test {
+        #[deboog(mask = "all")]
+        a: &'static str,
+        #[deboog(mask = "all")]
+        b: Option<i32>,
+        #[deboog(mask = "hidden")]
+        c: f64,
+    }
+    let _guard = policy::scoped(Policy::Reveal);
+    let our = Test {
+        a: "secret",
+        b: Some(123),
+        c: 1.5,
+    };
+    assert_eq!(
+        serde_json::to_value(&our).unwrap(),
+        json!({"a": "secret", "b": 123, "c": 1.5})
+    );
+}
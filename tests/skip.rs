@@ -15,6 +15,7 @@ fn skip_struct_field() {
 
 #[test]
 fn skip_tuple_field() {
+    #[allow(dead_code)]
     #[derive(Deboog)]
     struct Test(i32, #[deboog(skip)] i32);
     let our = Test(111, 222);
@@ -41,6 +42,7 @@ fn skip_variant_struct_field() {
 
 #[test]
 fn skip_variant_tuple_field() {
+    #[allow(dead_code)]
     #[derive(Deboog)]
     enum Test {
         One,
@@ -54,8 +56,10 @@ fn skip_variant_tuple_field() {
 
 #[test]
 fn nested_tuple_inner_skipped() {
+    #[allow(dead_code)]
     #[derive(Deboog)]
     struct Inner(#[deboog(skip)] i32);
+    #[allow(dead_code)]
     #[derive(Deboog)]
     struct Outer(Inner);
     let our = Outer(Inner(123));
@@ -64,10 +68,95 @@ fn nested_tuple_inner_skipped() {
 
 #[test]
 fn nested_tuple_outer_skipped() {
+    #[allow(dead_code)]
     #[derive(Deboog)]
     struct Inner(i32);
+    #[allow(dead_code)]
     #[derive(Deboog)]
     struct Outer(#[deboog(skip)] Inner);
     let our = Outer(Inner(123));
     assert_eq!(format!("{:?}", our), "Outer");
 }
+
+fn is_zero(value: &i32) -> bool {
+    *value == 0
+}
+
+#[test]
+fn skip_if_struct_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(skip_if = "Option::is_none")]
+        a: Option<i32>,
+        #[deboog(skip_if = "Vec::is_empty")]
+        b: Vec<i32>,
+        #[deboog(skip_if = "is_zero")]
+        c: i32,
+    }
+    let our = Test {
+        a: None,
+        b: vec![],
+        c: 0,
+    };
+    assert_eq!(format!("{:?}", our), "Test");
+    let our = Test {
+        a: Some(111),
+        b: vec![222],
+        c: 333,
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        "Test { a: Some(111), b: [222], c: 333 }"
+    );
+}
+
+#[test]
+fn skip_if_tuple_field() {
+    #[derive(Deboog)]
+    struct Test(i32, #[deboog(skip_if = "Option::is_none")] Option<i32>);
+    let our = Test(111, None);
+    assert_eq!(format!("{:?}", our), "Test(111)");
+    let our = Test(111, Some(222));
+    assert_eq!(format!("{:?}", our), "Test(111, Some(222))");
+}
+
+#[test]
+fn skip_if_masked_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all", skip_if = "Option::is_none")]
+        a: Option<&'static str>,
+    }
+    let our = Test { a: None };
+    assert_eq!(format!("{:?}", our), "Test");
+    let our = Test { a: Some("secret") };
+    assert_eq!(format!("{:?}", our), r#"Test { a: Some("******") }"#);
+}
+
+#[test]
+fn skip_if_variant_fields() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    enum Test {
+        Tuple(#[deboog(skip_if = "is_zero")] i32, i32),
+        Struct {
+            #[deboog(skip_if = "Option::is_none")]
+            a: Option<i32>,
+            #[deboog(skip)]
+            b: i32,
+            builder: i32,
+        },
+    }
+    let our = Test::Tuple(0, 222);
+    assert_eq!(format!("{:?}", our), "Tuple(222)");
+    let our = Test::Tuple(111, 222);
+    assert_eq!(format!("{:?}", our), "Tuple(111, 222)");
+    let our = Test::Struct {
+        a: None,
+        b: 222,
+        builder: 333,
+    };
+    assert_eq!(format!("{:?}", our), "Struct { builder: 333 }");
+}
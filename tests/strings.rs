@@ -0,0 +1,65 @@
+use std::{
+    ffi::{CString, OsString},
+    path::{Path, PathBuf},
+};
+
+use deboog::Deboog;
+
+#[test]
+fn mask_char() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        plain: char,
+        #[deboog(mask = "all")]
+        masked: char,
+    }
+    let our = Test {
+        plain: 'a',
+        masked: 'ф',
+    };
+    assert_eq!(format!("{:?}", our), r#"Test { plain: 'a', masked: '*' }"#);
+}
+
+#[test]
+fn mask_paths() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask(keep_start = 6))]
+        path: &'static Path,
+        #[deboog(mask(keep_start = 6))]
+        path_buf: PathBuf,
+    }
+    let our = Test {
+        path: Path::new("/home/user"),
+        path_buf: PathBuf::from("/home/user/.ssh/id_rsa"),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { path: "/home/****", path_buf: "/home/****************" }"#
+    );
+}
+
+#[test]
+fn mask_ffi_strings() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all")]
+        os: OsString,
+        #[deboog(mask = "pan_suffix")]
+        c: CString,
+        #[deboog(mask = "all")]
+        c_ref: &'static std::ffi::CStr,
+    }
+    let our = Test {
+        os: OsString::from("secret"),
+        c: CString::new("0123456789").unwrap(),
+        c_ref: c"secret",
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { os: "******", c: "*6789", c_ref: "******" }"#
+    );
+}
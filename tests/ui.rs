@@ -0,0 +1,10 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    // Compiler spells out full std type paths in diagnostics when `valuable` crate is linked
+    #[cfg(not(feature = "valuable"))]
+    t.compile_fail("tests/ui/trimmed_paths/*.rs");
+    #[cfg(not(feature = "hash"))]
+    t.compile_fail("tests/ui/hash_feature/*.rs");
+}
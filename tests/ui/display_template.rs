@@ -0,0 +1,25 @@
+use deboog::DeboogDisplay;
+
+#[derive(DeboogDisplay)]
+#[deboog(display = "{name} <{mail}>")]
+struct Test {
+    name: &'static str,
+    #[deboog(mask = "email")]
+    email: &'static str,
+}
+
+#[derive(DeboogDisplay)]
+enum Event {
+    #[deboog(display = "{}")]
+    Login(&'static str),
+    Logout,
+}
+
+#[derive(DeboogDisplay)]
+#[deboog(display = "{email:?}")]
+struct Debugged {
+    #[deboog(mask = "email")]
+    email: &'static str,
+}
+
+fn main() {}
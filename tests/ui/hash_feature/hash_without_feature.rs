@@ -0,0 +1,9 @@
+use deboog::Deboog;
+
+#[derive(Deboog)]
+struct Test {
+    #[deboog(mask = "hash")]
+    token: String,
+}
+
+fn main() {}
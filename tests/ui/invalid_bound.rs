@@ -0,0 +1,9 @@
+use deboog::Deboog;
+
+#[derive(Deboog)]
+#[deboog(bound = "T Debug")]
+struct Test<T> {
+    value: T,
+}
+
+fn main() {}
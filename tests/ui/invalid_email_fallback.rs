@@ -0,0 +1,11 @@
+use deboog::Deboog;
+
+#[derive(Deboog)]
+struct Test {
+    #[deboog(mask(email(fallback = "hidden")))]
+    login: &'static str,
+    #[deboog(mask(email(fallback = "unknown")))]
+    contact: &'static str,
+}
+
+fn main() {}
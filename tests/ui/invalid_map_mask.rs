@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+
+use deboog::Deboog;
+
+#[derive(Deboog)]
+struct Test {
+    #[deboog(mask = "all", mask_keys = "all")]
+    conflict: HashMap<String, String>,
+    #[deboog(mask_values = "hidden")]
+    hidden: HashMap<String, String>,
+}
+
+fn main() {}
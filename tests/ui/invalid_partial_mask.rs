@@ -0,0 +1,11 @@
+use deboog::Deboog;
+
+#[derive(Deboog)]
+struct Test {
+    #[deboog(mask(keep_start = 2, keep_tail = 3))]
+    account: &'static str,
+    #[deboog(mask(keep_start = -1, char = "##"))]
+    phone: &'static str,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use deboog::Deboog;
+
+#[derive(Deboog)]
+#[deboog(rename_all = "camel")]
+struct Test {
+    value: i32,
+}
+
+#[derive(Deboog)]
+struct Tuple(#[deboog(rename = "value")] i32);
+
+#[derive(Deboog)]
+#[deboog(rename = "Other")]
+enum Enum {
+    One,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use deboog::Deboog;
+
+#[derive(Deboog)]
+struct Test {
+    #[deboog(mask = 1)]
+    value: i32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use deboog::Deboog;
+
+#[derive(Deboog)]
+struct Test {
+    #[deboog(mask = "all", placeholder = "<redacted>")]
+    masked: &'static str,
+    #[deboog(placeholder = "<redacted>")]
+    unmasked: &'static str,
+}
+
+fn main() {}
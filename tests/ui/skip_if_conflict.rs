@@ -0,0 +1,9 @@
+use deboog::Deboog;
+
+#[derive(Deboog)]
+struct Test {
+    #[deboog(skip, skip_if = "Option::is_none")]
+    value: Option<i32>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use deboog::Deboog;
+
+fn fmt_value(_value: &String, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("value")
+}
+
+#[derive(Deboog)]
+struct Test {
+    #[deboog(with = "fmt_value")]
+    value: i32,
+}
+
+fn main() {}
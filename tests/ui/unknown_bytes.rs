@@ -0,0 +1,9 @@
+use deboog::Deboog;
+
+#[derive(Deboog)]
+struct Test {
+    #[deboog(bytes = "base32")]
+    key: Vec<u8>,
+}
+
+fn main() {}
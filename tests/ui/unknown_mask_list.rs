@@ -0,0 +1,13 @@
+use deboog::Deboog;
+
+#[derive(Deboog)]
+struct Test {
+    #[deboog(mask(email(keep_domian = false)))]
+    email: &'static str,
+    #[deboog(mask(secret(keep = 2)))]
+    secret: &'static str,
+    #[deboog(mask(pan, all))]
+    pan: &'static str,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use deboog::Deboog;
+
+#[derive(Deboog)]
+enum Test {
+    One(#[deboog(mask = "everything")] i32),
+    Two {
+        #[deboog(hide)]
+        value: i32,
+    },
+}
+
+fn main() {}
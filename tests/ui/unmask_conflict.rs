@@ -0,0 +1,10 @@
+use deboog::Deboog;
+
+#[derive(Deboog)]
+#[deboog(mask = "all")]
+struct Test {
+    #[deboog(mask = "pan", unmask)]
+    value: &'static str,
+}
+
+fn main() {}
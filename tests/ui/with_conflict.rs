@@ -0,0 +1,13 @@
+use deboog::Deboog;
+
+fn fmt_value(_value: &i32, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("value")
+}
+
+#[derive(Deboog)]
+struct Test {
+    #[deboog(mask = "all", with = "fmt_value")]
+    value: i32,
+}
+
+fn main() {}
@@ -0,0 +1,299 @@
+#![cfg(feature = "valuable")]
+
+use std::collections::BTreeMap;
+
+use deboog::{valuable::export::Valuable, DeboogValuable};
+
+fn recorded<T: Valuable>(value: &T) -> String {
+    format!("{:?}", value.as_value())
+}
+
+#[test]
+fn valuable_struct() {
+    #[allow(dead_code)]
+    #[derive(DeboogValuable)]
+    struct Test {
+        id: i32,
+        #[deboog(mask = "pan")]
+        card: String,
+        #[deboog(mask = "all")]
+        pin: u16,
+        #[deboog(skip)]
+        password: String,
+        #[deboog(mask = "hidden")]
+        token: String,
+        #[deboog(mask = "hidden", placeholder = "<redacted>")]
+        secret: String,
+        #[deboog(rename = "mail", mask = "email")]
+        email: Option<&'static str>,
+    }
+    let our = Test {
+        id: 123,
+        card: "0123456789012345".into(),
+        pin: 1234,
+        password: "qwerty".into(),
+        token: "abc".into(),
+        secret: "def".into(),
+        email: Some("john@example.com"),
+    };
+    assert_eq!(
+        recorded(&our),
+        r#"Test { id: 123, card: "012345******2345", pin: "****", token: "***", secret: "<redacted>", mail: "j***@example.com" }"#
+    );
+}
+
+#[test]
+fn valuable_collections() {
+    #[allow(dead_code)]
+    #[derive(DeboogValuable)]
+    struct Test {
+        #[deboog(mask = "all")]
+        list: Vec<Option<&'static str>>,
+        #[deboog(mask = "pan_suffix")]
+        tuple: (i32, String),
+        #[deboog(mask_values = "all")]
+        map: BTreeMap<&'static str, &'static str>,
+        #[deboog(mask = "all")]
+        masked_map: BTreeMap<&'static str, u8>,
+        #[deboog(bytes = "hex", mask = "pan_suffix")]
+        bytes: Vec<u8>,
+    }
+    let our = Test {
+        list: vec![Some("abc"), None],
+        tuple: (123456, "0123456789".into()),
+        map: BTreeMap::from([("key", "value")]),
+        masked_map: BTreeMap::from([("key", 5)]),
+        bytes: vec![0xde, 0xad, 0xbe, 0xef],
+    };
+    assert_eq!(
+        recorded(&our),
+        r#"Test { list: ["***", ()], tuple: ("*3456", "*6789"), map: {"key": "*****"}, masked_map: {"***": "*"}, bytes: "*beef" }"#
+    );
+}
+
+#[test]
+fn valuable_map_keys_or_values_of_plain_types() {
+    #[derive(DeboogValuable)]
+    struct Test {
+        #[deboog(mask_values = "all")]
+        by_unit: BTreeMap<(), String>,
+        #[deboog(mask_keys = "pan_suffix")]
+        to_unit: BTreeMap<String, ()>,
+    }
+    let our = Test {
+        by_unit: BTreeMap::from([((), "abc".into())]),
+        to_unit: BTreeMap::from([("0123456789".into(), ())]),
+    };
+    assert_eq!(
+        recorded(&our),
+        r#"Test { by_unit: {(): "***"}, to_unit: {"*6789": ()} }"#
+    );
+}
+
+#[test]
+fn valuable_generic_map_keys_or_values() {
+    #[derive(DeboogValuable)]
+    struct Test<K, V> {
+        #[deboog(mask_keys = "pan_suffix")]
+        to_unit: BTreeMap<K, V>,
+        #[deboog(mask_values = "all")]
+        by_unit: BTreeMap<V, K>,
+    }
+    let our = Test::<String, ()> {
+        to_unit: BTreeMap::from([("0123456789".into(), ())]),
+        by_unit: BTreeMap::from([((), "abc".into())]),
+    };
+    assert_eq!(
+        recorded(&our),
+        r#"Test { to_unit: {"*6789": ()}, by_unit: {(): "***"} }"#
+    );
+}
+
+#[test]
+fn valuable_wrappers() {
+    use std::{
+        cell::RefCell,
+        pin::Pin,
+        sync::{Mutex, RwLock},
+    };
+
+    #[derive(DeboogValuable)]
+    struct Test {
+        #[deboog(mask = "all")]
+        mutex: Mutex<String>,
+        #[deboog(mask = "all")]
+        rw_lock: RwLock<Vec<String>>,
+        #[deboog(mask = "all")]
+        cell: RefCell<String>,
+        #[deboog(mask = "all")]
+        pinned: Pin<Box<str>>,
+        #[deboog(mask = "all")]
+        ok: Result<String, String>,
+        #[deboog(mask = "pan_suffix")]
+        err: Result<u32, String>,
+    }
+    let our = Test {
+        mutex: Mutex::new("abc".into()),
+        rw_lock: RwLock::new(vec!["de".into()]),
+        cell: RefCell::new("fghi".into()),
+        pinned: Pin::from(Box::<str>::from("jk")),
+        ok: Ok("lmn".into()),
+        err: Err("0123456789".into()),
+    };
+    assert_eq!(
+        recorded(&our),
+        r#"Test { mutex: "***", rw_lock: ["**"], cell: "****", pinned: "**", ok: Result::Ok("***"), err: Result::Err("*6789") }"#
+    );
+    let _mutex = our.mutex.lock().unwrap();
+    let _rw_lock = our.rw_lock.write().unwrap();
+    let _cell = our.cell.borrow_mut();
+    assert_eq!(
+        recorded(&our),
+        r#"Test { mutex: "<locked>", rw_lock: "<locked>", cell: "<borrowed>", pinned: "**", ok: Result::Ok("***"), err: Result::Err("*6789") }"#
+    );
+}
+
+#[test]
+fn valuable_skip_if_with() {
+    fn is_empty(value: &&str) -> bool {
+        value.is_empty()
+    }
+
+    fn first_char(value: &&str, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}…", value.chars().next().unwrap_or_default())
+    }
+
+    #[derive(DeboogValuable)]
+    struct Test {
+        #[deboog(skip_if = "is_empty")]
+        name: &'static str,
+        #[deboog(with = "first_char")]
+        code: &'static str,
+    }
+    assert_eq!(
+        recorded(&Test {
+            name: "",
+            code: "secret"
+        }),
+        r#"Test { code: "s…" }"#
+    );
+    assert_eq!(
+        recorded(&Test {
+            name: "john",
+            code: "secret"
+        }),
+        r#"Test { name: "john", code: "s…" }"#
+    );
+}
+
+#[test]
+fn valuable_tuple_unit() {
+    #[derive(DeboogValuable)]
+    struct Tuple(u32, #[deboog(mask = "all")] &'static str);
+
+    #[derive(DeboogValuable)]
+    struct Unit;
+
+    assert_eq!(recorded(&Tuple(1, "abc")), r#"Tuple(1, "***")"#);
+    assert_eq!(recorded(&Unit), "Unit");
+}
+
+#[test]
+fn valuable_enum() {
+    #[allow(dead_code)]
+    #[derive(DeboogValuable)]
+    enum Test {
+        Unit,
+        Tuple(u8, #[deboog(mask = "all")] &'static str),
+        #[deboog(rename = "Named")]
+        Struct {
+            id: u8,
+            #[deboog(mask = "pan_suffix")]
+            card: &'static str,
+            #[deboog(skip)]
+            password: &'static str,
+        },
+    }
+    assert_eq!(recorded(&Test::Unit), "Test::Unit");
+    assert_eq!(recorded(&Test::Tuple(1, "abc")), r#"Test::Tuple(1, "***")"#);
+    assert_eq!(
+        recorded(&Test::Struct {
+            id: 2,
+            card: "0123456789",
+            password: "qwerty",
+        }),
+        r#"Test::Named { id: 2, card: "*6789" }"#
+    );
+}
+
+#[test]
+fn valuable_enum_skip_if() {
+    use deboog::valuable::export::{Enumerable, Variant};
+
+    #[derive(DeboogValuable)]
+    enum Test {
+        Plain(u8),
+        Named {
+            id: u8,
+            #[deboog(skip_if = "Option::is_none", mask = "all")]
+            note: Option<&'static str>,
+        },
+        Tuple(#[deboog(skip_if = "Option::is_none")] Option<u8>, u8),
+    }
+    assert!(Test::Plain(1).definition().is_dynamic());
+    assert_eq!(Test::Plain(1).definition().variants().len(), 1);
+    assert!(matches!(Test::Plain(1).variant(), Variant::Static(_)));
+    let our = Test::Named { id: 1, note: None };
+    assert!(matches!(our.variant(), Variant::Dynamic(_)));
+    assert_eq!(recorded(&our), "Test::Named { id: 1 }");
+    let our = Test::Named {
+        id: 2,
+        note: Some("abc"),
+    };
+    assert_eq!(recorded(&our), r#"Test::Named { id: 2, note: "***" }"#);
+    assert_eq!(recorded(&Test::Tuple(None, 3)), "Test::Tuple(3)");
+    assert_eq!(recorded(&Test::Tuple(Some(4), 5)), "Test::Tuple(4, 5)");
+}
+
+#[test]
+fn valuable_generic() {
+    #[derive(DeboogValuable)]
+    struct Test<T, U> {
+        plain: T,
+        #[deboog(mask = "all")]
+        masked: U,
+    }
+    assert_eq!(
+        recorded(&Test {
+            plain: 1u8,
+            masked: "abc"
+        }),
+        r#"Test { plain: 1, masked: "***" }"#
+    );
+}
+
+#[cfg(any(debug_assertions, feature = "reveal-in-release"))]
+#[test]
+fn valuable_reveal() {
+    use deboog::policy::{self, Policy};
+
+    #[derive(DeboogValuable)]
+    struct Test {
+        #[deboog(mask = "pan")]
+        card: &'static str,
+        #[deboog(mask = "all")]
+        pin: u16,
+        #[deboog(mask = "hidden")]
+        token: &'static str,
+    }
+    let our = Test {
+        card: "0123456789012345",
+        pin: 1234,
+        token: "abc",
+    };
+    let _guard = policy::scoped(Policy::Reveal);
+    assert_eq!(
+        recorded(&our),
+        r#"Test { card: "0123456789012345", pin: 1234, token: "abc" }"#
+    );
+}
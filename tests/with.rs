@@ -0,0 +1,102 @@
+use std::{
+    fmt::{self, Formatter},
+    time::Duration,
+};
+
+use deboog::Deboog;
+
+fn fmt_secs(value: &Duration, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "{}s", value.as_secs())
+}
+
+fn fmt_len<T: AsRef<[u8]>>(value: &T, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "[{} bytes]", value.as_ref().len())
+}
+
+mod formatters {
+    use std::fmt::{self, Formatter};
+
+    pub fn redacted<T>(_value: &T, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+#[test]
+fn with_struct_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(with = "fmt_secs")]
+        timeout: Duration,
+        #[deboog(with = "fmt_len")]
+        key: Vec<u8>,
+        #[deboog(with = "formatters::redacted")]
+        token: &'static str,
+    }
+    let our = Test {
+        timeout: Duration::from_secs(30),
+        key: vec![1, 2, 3, 4],
+        token: "secret",
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        "Test { timeout: 30s, key: [4 bytes], token: <redacted> }"
+    );
+}
+
+#[test]
+fn with_tuple_field() {
+    #[derive(Deboog)]
+    struct Test(i32, #[deboog(with = "fmt_secs")] Duration);
+    let our = Test(123, Duration::from_secs(30));
+    assert_eq!(format!("{:?}", our), "Test(123, 30s)");
+}
+
+#[test]
+fn with_variant_fields() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    enum Test {
+        Tuple(#[deboog(with = "fmt_secs")] Duration),
+        Struct {
+            #[deboog(with = "fmt_len")]
+            key: Vec<u8>,
+        },
+    }
+    let our = Test::Tuple(Duration::from_secs(30));
+    assert_eq!(format!("{:?}", our), "Tuple(30s)");
+    let our = Test::Struct { key: vec![1, 2] };
+    assert_eq!(format!("{:?}", our), "Struct { key: [2 bytes] }");
+}
+
+#[test]
+fn with_container_mask() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    #[deboog(mask = "all")]
+    struct Test {
+        token: &'static str,
+        #[deboog(with = "fmt_secs")]
+        timeout: Duration,
+    }
+    let our = Test {
+        token: "secret",
+        timeout: Duration::from_secs(30),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { token: "******", timeout: 30s }"#
+    );
+}
+
+#[test]
+fn with_generic_field() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test<T> {
+        #[deboog(with = "formatters::redacted")]
+        value: T,
+    }
+    let our = Test { value: 123 };
+    assert_eq!(format!("{:?}", our), "Test { value: <redacted> }");
+}
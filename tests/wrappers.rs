@@ -0,0 +1,131 @@
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    cmp::Reverse,
+    num::Wrapping,
+    pin::Pin,
+    rc::Rc,
+    sync::{Arc, Mutex, RwLock},
+};
+
+use deboog::Deboog;
+
+#[test]
+fn mask_smart_pointers() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all")]
+        boxed: Box<str>,
+        #[deboog(mask = "all")]
+        rc: Rc<String>,
+        #[deboog(mask = "pan_suffix")]
+        arc: Arc<str>,
+        #[deboog(mask = "all")]
+        pinned: Pin<Box<i32>>,
+    }
+    let our = Test {
+        boxed: "secret".into(),
+        rc: Rc::new("secret".into()),
+        arc: "0123456789".into(),
+        pinned: Box::pin(12345),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { boxed: "******", rc: "******", arc: "*6789", pinned: ***** }"#
+    );
+}
+
+#[test]
+fn mask_cow() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test<'a> {
+        #[deboog(mask = "all")]
+        borrowed: Cow<'a, str>,
+        #[deboog(mask = "all")]
+        owned: Cow<'a, str>,
+    }
+    let our = Test {
+        borrowed: Cow::Borrowed("secret"),
+        owned: Cow::Owned("secret".into()),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { borrowed: "******", owned: "******" }"#
+    );
+}
+
+#[test]
+fn mask_std_wrappers() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all")]
+        wrapping: Wrapping<u32>,
+        #[deboog(mask = "all")]
+        reverse: Reverse<i32>,
+        #[deboog(mask = "all")]
+        ok: Result<&'static str, i32>,
+        #[deboog(mask = "all")]
+        err: Result<&'static str, i32>,
+    }
+    let our = Test {
+        wrapping: Wrapping(123),
+        reverse: Reverse(4567),
+        ok: Ok("secret"),
+        err: Err(404),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { wrapping: ***, reverse: Reverse(****), ok: Ok("******"), err: Err(***) }"#
+    );
+}
+
+#[test]
+fn mask_ref_cell() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all")]
+        cell: RefCell<String>,
+    }
+    let our = Test {
+        cell: RefCell::new("secret".into()),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { cell: RefCell { value: "******" } }"#
+    );
+    let _borrow = our.cell.borrow_mut();
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { cell: RefCell { value: <borrowed> } }"#
+    );
+}
+
+#[test]
+fn mask_locks() {
+    #[allow(dead_code)]
+    #[derive(Deboog)]
+    struct Test {
+        #[deboog(mask = "all")]
+        mutex: Mutex<String>,
+        #[deboog(mask = "all")]
+        rwlock: RwLock<i32>,
+    }
+    let our = Test {
+        mutex: Mutex::new("secret".into()),
+        rwlock: RwLock::new(123),
+    };
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { mutex: Mutex { data: "******", poisoned: false, .. }, rwlock: RwLock { data: ***, poisoned: false, .. } }"#
+    );
+    let _guard = our.mutex.lock().unwrap();
+    let _write = our.rwlock.write().unwrap();
+    assert_eq!(
+        format!("{:?}", our),
+        r#"Test { mutex: Mutex { data: <locked>, poisoned: false, .. }, rwlock: RwLock { data: <locked>, poisoned: false, .. } }"#
+    );
+}